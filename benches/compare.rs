@@ -0,0 +1,58 @@
+//! A benchmark comparing version number strings.
+//!
+//! This times comparing all numeric versions, which take the fast path, against comparing them by
+//! parsing them first, and against comparing versions having text parts.
+//!
+//! Run this benchmark by invoking `cargo bench`.
+
+use std::time::Instant;
+
+use version_compare::{compare, Cmp, Version};
+
+/// The number of times to compare each pair of versions.
+const ITERATIONS: u32 = 200_000;
+
+/// Pairs of all numeric versions.
+const NUMERIC: &[(&str, &str)] = &[
+    ("1.2.3", "1.2.4"),
+    ("1.10", "1.9.9"),
+    ("2.0", "2.0.0.0"),
+    ("20240101", "20231231.1"),
+];
+
+/// Pairs of versions having text parts.
+const TEXT: &[(&str, &str)] = &[
+    ("1.2.3-rc1", "1.2.3"),
+    ("v1.10", "v1.9.9"),
+    ("2.0-beta.2", "2.0-beta.11"),
+    ("1.2.3 build 5", "1.2.3"),
+];
+
+/// Time comparing each pair of versions with the given function, and print the result.
+///
+/// The results are summed and printed, so comparing can't be optimized away.
+fn bench(name: &str, pairs: &[(&str, &str)], f: impl Fn(&str, &str) -> Cmp) {
+    let start = Instant::now();
+    let mut sum = 0;
+    for _ in 0..ITERATIONS {
+        for (a, b) in pairs {
+            sum += f(a, b) as u32;
+        }
+    }
+    let elapsed = start.elapsed();
+    let count = ITERATIONS * pairs.len() as u32;
+    println!(
+        "{:<10} {:>8.1} ns/compare (sum {})",
+        name,
+        elapsed.as_nanos() as f64 / f64::from(count),
+        sum,
+    );
+}
+
+fn main() {
+    bench("numeric", NUMERIC, |a, b| compare(a, b).unwrap());
+    bench("parsed", NUMERIC, |a, b| {
+        Version::from(a).unwrap().compare(Version::from(b).unwrap())
+    });
+    bench("text", TEXT, |a, b| compare(a, b).unwrap());
+}
@@ -0,0 +1,4 @@
+fn main() {
+    // Declare the cfg set by cargo-tarpaulin, Cargo versions without check-cfg skip this
+    println!("cargo:rustc-check-cfg=cfg(tarpaulin)");
+}
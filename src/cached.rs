@@ -0,0 +1,233 @@
+//! Cached version module.
+//!
+//! A module that provides the `CachedVersion` struct, to efficiently compare a single parsed
+//! version against many version strings.
+
+use crate::{Cmp, Manifest, Scheme, Version};
+
+/// Cached version struct, wrapping a parsed base `Version`.
+///
+/// This is useful to compare the same base version against many version strings, such as when
+/// checking whether an installed version satisfies many requirements. The base version is parsed
+/// once, only the version strings it is compared against are parsed on each comparison.
+///
+/// Version strings are parsed with the manifest and scheme of the base version.
+///
+/// Only generic versions benefit from the cache. Versions following a scheme other than
+/// `Scheme::Generic` are compared with `Scheme::compare`, which parses the version string of the
+/// base version again on each comparison.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, CachedVersion};
+///
+/// let installed = CachedVersion::from("1.2.3").unwrap();
+///
+/// assert_eq!(installed.compare("1.2.3"), Ok(Cmp::Eq));
+/// assert_eq!(installed.compare("1.10"), Ok(Cmp::Lt));
+/// assert_eq!(installed.compare_to("1.0", Cmp::Ge), Ok(true));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CachedVersion<'a> {
+    version: Version<'a>,
+}
+
+impl<'a> CachedVersion<'a> {
+    /// Create a cached version from an already parsed `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CachedVersion, Version};
+    ///
+    /// let cached = CachedVersion::new(Version::from("1.2.3").unwrap());
+    /// ```
+    pub fn new(version: Version<'a>) -> Self {
+        CachedVersion { version }
+    }
+
+    /// Create a cached version from a version string.
+    ///
+    /// `None` is returned if the version string is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::CachedVersion;
+    ///
+    /// assert!(CachedVersion::from("1.2.3").is_some());
+    /// assert!(CachedVersion::from("abc").is_none());
+    /// ```
+    pub fn from(version: &'a str) -> Option<Self> {
+        Version::from(version).map(Self::new)
+    }
+
+    /// Create a cached version from a version string with the given `manifest`.
+    ///
+    /// The manifest is also used to parse the version strings this is compared against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, CachedVersion, Manifest};
+    ///
+    /// let manifest = Manifest::builder().max_depth(2).build();
+    /// let cached = CachedVersion::from_manifest("1.2.3", &manifest).unwrap();
+    ///
+    /// assert_eq!(cached.compare("1.2.4"), Ok(Cmp::Eq));
+    /// ```
+    pub fn from_manifest(version: &'a str, manifest: &'a Manifest) -> Option<Self> {
+        Version::from_manifest(version, manifest).map(Self::new)
+    }
+
+    /// Get the cached base version.
+    pub fn version(&self) -> &Version<'a> {
+        &self.version
+    }
+
+    /// Compare the cached version to the given `other` version string.
+    ///
+    /// This compares the cached version to version `other`, and returns whether the cached
+    /// version is greater, less or equal to `other`.
+    ///
+    /// If the `other` version string is invalid an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, CachedVersion};
+    ///
+    /// let cached = CachedVersion::from("1.2.3").unwrap();
+    ///
+    /// assert_eq!(cached.compare("1.2.4"), Ok(Cmp::Lt));
+    /// assert_eq!(cached.compare("abc"), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn compare(&self, other: &str) -> Result<Cmp, ()> {
+        let other = self.parse(other).ok_or(())?;
+        Ok(self.base().compare(other))
+    }
+
+    /// Compare the cached version to the given `other` version string, and check whether the
+    /// given comparison `operator` is valid.
+    ///
+    /// If the `other` version string is invalid an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, CachedVersion};
+    ///
+    /// let cached = CachedVersion::from("1.2.3").unwrap();
+    ///
+    /// assert_eq!(cached.compare_to("1.2", Cmp::Gt), Ok(true));
+    /// assert_eq!(cached.compare_to("1.2", Cmp::Le), Ok(false));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn compare_to(&self, other: &str, operator: Cmp) -> Result<bool, ()> {
+        let other = self.parse(other).ok_or(())?;
+        Ok(self.base().compare_to(other, operator))
+    }
+
+    /// Parse the given version string the same way as the cached version.
+    fn parse<'b>(&'b self, version: &'b str) -> Option<Version<'b>> {
+        match (self.version.scheme(), self.version.manifest()) {
+            (Scheme::Generic, Some(manifest)) => Version::from_manifest(version, manifest),
+            (Scheme::Generic, None) => Version::from(version),
+            (scheme, _) => Version::from_scheme(version, scheme),
+        }
+    }
+
+    /// Borrow the cached version with a shorter lifetime, to compare it to a parsed version.
+    fn base<'b>(&'b self) -> &'b Version<'b> {
+        &self.version
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::test::{COMBIS, COMBIS_ERROR};
+    use crate::version::SPLIT_COUNT;
+    use crate::{Cmp, Part, Scheme, Version};
+
+    use super::CachedVersion;
+
+    #[test]
+    fn compare() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            let cached = CachedVersion::from(entry.0).unwrap();
+            assert_eq!(cached.compare(entry.1), crate::compare(entry.0, entry.1));
+        }
+
+        // Compare each error version in the version set
+        for entry in COMBIS_ERROR {
+            if let Some(cached) = CachedVersion::from(entry.0) {
+                assert_eq!(cached.compare(entry.1), crate::compare(entry.0, entry.1));
+            }
+        }
+    }
+
+    #[test]
+    fn compare_to() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            let cached = CachedVersion::from(entry.0).unwrap();
+            assert_eq!(cached.compare_to(entry.1, entry.2), Ok(true));
+            assert_eq!(cached.compare_to(entry.1, entry.2.invert()), Ok(false));
+        }
+    }
+
+    #[test]
+    fn compare_manifest() {
+        // Compare each version in the version set having a custom manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_some()) {
+            let manifest = entry.3.as_ref().unwrap();
+            let cached = CachedVersion::from_manifest(entry.0, manifest).unwrap();
+            assert_eq!(cached.compare(entry.1), Ok(entry.2));
+        }
+    }
+
+    #[test]
+    fn compare_scheme() {
+        let cached = CachedVersion::new(Version::from_scheme("1.0", Scheme::Pep440).unwrap());
+        assert_eq!(cached.compare("1.0.post1"), Ok(Cmp::Lt));
+        assert_eq!(cached.compare("1.0 final"), Err(()));
+    }
+
+    #[test]
+    fn base_parsed_once() {
+        let splits = || SPLIT_COUNT.with(Cell::get);
+
+        // Comparing generically only parses the other version
+        let cached = CachedVersion::from("1.2.3").unwrap();
+        let before = splits();
+        assert_eq!(cached.compare("1.2.4"), Ok(Cmp::Lt));
+        assert_eq!(cached.compare_to("1.2.3", Cmp::Eq), Ok(true));
+        assert_eq!(splits() - before, 2);
+
+        // Following a scheme, the base isn't split again either, but the scheme parses both
+        // version strings on each comparison
+        let cached = CachedVersion::new(Version::from_scheme("1.0", Scheme::Pep440).unwrap());
+        let before = splits();
+        assert_eq!(cached.compare("1.0.post1"), Ok(Cmp::Lt));
+        assert_eq!(splits() - before, 1);
+    }
+
+    #[test]
+    fn base_not_reparsed() {
+        // The parts don't match the version string, comparing generically must use the parts
+        let cached = CachedVersion::new(Version::from_parts(
+            "9.9",
+            vec![Part::Number(1), Part::Number(2)],
+        ));
+
+        assert_eq!(cached.compare("1.2"), Ok(Cmp::Eq));
+        assert_eq!(cached.compare("9.9"), Ok(Cmp::Lt));
+        assert_eq!(cached.version().as_str(), "9.9");
+    }
+}
@@ -0,0 +1,71 @@
+//! Release channel module.
+//!
+//! A module that provides the `Channel` enum, classifying a version by its release channel such
+//! as stable, beta or nightly.
+
+/// Release channel enum.
+///
+/// Derived from the pre-release text of a version with `Version::channel`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Channel {
+    /// Stable release, having no pre-release text.
+    Stable,
+
+    /// Beta pre-release, such as `1.0-beta` or `1.0b1`.
+    Beta,
+
+    /// Alpha pre-release, such as `1.0-alpha` or `1.0a1`.
+    Alpha,
+
+    /// Release candidate, such as `1.0-rc1` or `1.0-pre`.
+    Rc,
+
+    /// Development release, such as `1.0.dev3` or `1.0-SNAPSHOT`.
+    Dev,
+
+    /// Nightly build, such as `1.0-nightly`.
+    Nightly,
+
+    /// Any other channel.
+    ///
+    /// Holds the lowercase pre-release text, such as `post` for `1.0.post1`.
+    Other(String),
+}
+
+impl Channel {
+    /// Classify the channel of the given pre-release text part.
+    ///
+    /// Only the leading letters are used, so `rc1` is classified as `rc`.
+    pub(crate) fn from_text(text: &str) -> Channel {
+        let text = text.to_lowercase();
+        let name = text.trim_end_matches(|c: char| c.is_ascii_digit());
+        match name {
+            "" | "stable" | "final" | "release" | "ga" => Channel::Stable,
+            "beta" | "b" => Channel::Beta,
+            "alpha" | "a" => Channel::Alpha,
+            "rc" | "c" | "pre" | "preview" => Channel::Rc,
+            "dev" | "snapshot" => Channel::Dev,
+            "nightly" => Channel::Nightly,
+            name => Channel::Other(name.into()),
+        }
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use super::Channel;
+
+    #[test]
+    fn from_text() {
+        assert_eq!(Channel::from_text("beta"), Channel::Beta);
+        assert_eq!(Channel::from_text("b2"), Channel::Beta);
+        assert_eq!(Channel::from_text("Alpha"), Channel::Alpha);
+        assert_eq!(Channel::from_text("RC1"), Channel::Rc);
+        assert_eq!(Channel::from_text("dev3"), Channel::Dev);
+        assert_eq!(Channel::from_text("SNAPSHOT"), Channel::Dev);
+        assert_eq!(Channel::from_text("nightly"), Channel::Nightly);
+        assert_eq!(Channel::from_text("final"), Channel::Stable);
+        assert_eq!(Channel::from_text("post1"), Channel::Other("post".into()));
+    }
+}
@@ -17,6 +17,9 @@ pub enum Cmp {
 
     /// Not equal (`!=`, `!`, `<>`).
     /// When version `A` is not equal to `B`.
+    ///
+    /// Comparing versions never results in this operator, it is only meaningful as operator to
+    /// test against, such as with `compare_to`. Use `is_ne` to test for inequality.
     Ne,
 
     /// Less than (`<`).
@@ -50,6 +53,8 @@ impl Cmp {
     /// * `>=` -> `Ge`
     /// * `> ` -> `Gt`
     ///
+    /// Reversed signs such as `=>` and `=<` are not supported, an error is returned for them.
+    ///
     /// # Examples
     ///
     /// ```
@@ -100,6 +105,27 @@ impl Cmp {
         }
     }
 
+    /// Get a comparison operator by it's sign or name.
+    ///
+    /// The string is first parsed as sign, see `from_sign`, and then as name, see `from_name`.
+    /// This is useful to accept both forms, such as in command line arguments.
+    /// An error is returned if neither is recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Cmp;
+    ///
+    /// assert_eq!(Cmp::parse("<="), Ok(Cmp::Le));
+    /// assert_eq!(Cmp::parse("le"), Ok(Cmp::Le));
+    /// assert_eq!(Cmp::parse("  LE "), Ok(Cmp::Le));
+    /// assert!(Cmp::parse("abc").is_err());
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn parse<S: AsRef<str>>(s: S) -> Result<Cmp, ()> {
+        Self::from_sign(s.as_ref()).or_else(|_| Self::from_name(s.as_ref()))
+    }
+
     /// Get the comparison operator from Rusts `Ordering` enum.
     ///
     /// The following comparison operators are returned:
@@ -112,6 +138,35 @@ impl Cmp {
         Self::from(ord)
     }
 
+    /// Get the comparison operator from a factor number, the inverse of `factor`.
+    ///
+    /// This is useful for C-style comparators returning an integer. The following comparison
+    /// operators are returned:
+    ///
+    /// * `-1` -> `Lt`
+    /// * ` 0` -> `Eq`
+    /// * ` 1` -> `Gt`
+    ///
+    /// For any other number `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Cmp;
+    ///
+    /// assert_eq!(Cmp::from_ordering_i8(-1), Some(Cmp::Lt));
+    /// assert_eq!(Cmp::from_ordering_i8(0), Some(Cmp::Eq));
+    /// assert_eq!(Cmp::from_ordering_i8(2), None);
+    /// ```
+    pub const fn from_ordering_i8(factor: i8) -> Option<Cmp> {
+        match factor {
+            -1 => Some(Cmp::Lt),
+            0 => Some(Cmp::Eq),
+            1 => Some(Cmp::Gt),
+            _ => None,
+        }
+    }
+
     /// Get the name of this comparison operator.
     ///
     /// # Examples
@@ -354,6 +409,18 @@ mod tests {
         // Exceptional cases
         assert_eq!(Cmp::from_sign("  <=  ").unwrap(), Cmp::Le);
         assert_eq!(Cmp::from_sign("*"), Err(()));
+
+        // Aliases give the same operator as the regular signs
+        assert_eq!(Cmp::from_sign("="), Cmp::from_sign("=="));
+        assert_eq!(Cmp::from_sign("<>"), Cmp::from_sign("!="));
+        assert_eq!(Cmp::parse("="), Ok(Cmp::Eq));
+        assert_eq!(Cmp::parse(" <> "), Ok(Cmp::Ne));
+
+        // Reversed signs are rejected
+        for sign in ["=>", "=<", "><", "=!", "==="] {
+            assert_eq!(Cmp::from_sign(sign), Err(()), "{} must be rejected", sign);
+            assert_eq!(Cmp::parse(sign), Err(()), "{} must be rejected", sign);
+        }
     }
 
     #[test]
@@ -371,6 +438,23 @@ mod tests {
         assert_eq!(Cmp::from_name("abc"), Err(()));
     }
 
+    #[test]
+    fn parse() {
+        // Signs and names
+        assert_eq!(Cmp::parse("<=").unwrap(), Cmp::Le);
+        assert_eq!(Cmp::parse("le").unwrap(), Cmp::Le);
+        assert_eq!(Cmp::parse("  LE ").unwrap(), Cmp::Le);
+        assert_eq!(Cmp::parse("=").unwrap(), Cmp::Eq);
+        assert_eq!(Cmp::parse("<>").unwrap(), Cmp::Ne);
+        assert_eq!(Cmp::parse("Gt").unwrap(), Cmp::Gt);
+
+        // Exceptional cases
+        assert_eq!(Cmp::parse("*"), Err(()));
+        assert_eq!(Cmp::parse("abc"), Err(()));
+        assert_eq!(Cmp::parse("<= le"), Err(()));
+        assert_eq!(Cmp::parse(""), Err(()));
+    }
+
     #[test]
     fn from_ord() {
         assert_eq!(Cmp::from(Ordering::Less), Cmp::Lt);
@@ -378,6 +462,27 @@ mod tests {
         assert_eq!(Cmp::from(Ordering::Greater), Cmp::Gt);
     }
 
+    #[test]
+    fn from_ordering_i8() {
+        assert_eq!(Cmp::from_ordering_i8(-1), Some(Cmp::Lt));
+        assert_eq!(Cmp::from_ordering_i8(0), Some(Cmp::Eq));
+        assert_eq!(Cmp::from_ordering_i8(1), Some(Cmp::Gt));
+        assert_eq!(Cmp::from_ordering_i8(-2), None);
+        assert_eq!(Cmp::from_ordering_i8(i8::MAX), None);
+
+        // Round trip through the factor
+        for cmp in [Cmp::Lt, Cmp::Eq, Cmp::Gt] {
+            assert_eq!(Cmp::from_ordering_i8(cmp.factor()), Some(cmp));
+        }
+        assert_eq!(Cmp::from_ordering_i8(Cmp::Le.factor()), Some(Cmp::Lt));
+        assert_eq!(Cmp::from_ordering_i8(Cmp::Ne.factor()), Some(Cmp::Eq));
+        assert_eq!(Cmp::from_ordering_i8(Cmp::Ge.factor()), Some(Cmp::Gt));
+
+        // Usable in constant context
+        const LT: Option<Cmp> = Cmp::from_ordering_i8(-1);
+        assert_eq!(LT, Some(Cmp::Lt));
+    }
+
     #[test]
     fn name() {
         assert_eq!(Cmp::Eq.name(), "eq");
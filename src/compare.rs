@@ -1,7 +1,10 @@
 //! Version compare module, with useful static comparison methods.
 
-use crate::version::Version;
-use crate::Cmp;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use crate::version::{satisfies, Version};
+use crate::{Cmp, CompareOptions, Manifest, Part, Scheme};
 
 /// Compare two version number strings to each other.
 ///
@@ -16,6 +19,9 @@ use crate::Cmp;
 /// * `Cmp::Lt`
 /// * `Cmp::Gt`
 ///
+/// `Cmp::Ne` is never returned, as unequal versions are always less or greater. Use
+/// `compare_to` or `is_ne` to test for inequality.
+///
 /// # Examples
 ///
 /// ```
@@ -31,89 +37,1782 @@ where
     A: AsRef<str>,
     B: AsRef<str>,
 {
+    if let Some(cmp) = compare_numeric(a.as_ref(), b.as_ref()) {
+        return Ok(cmp);
+    }
+
     let a = Version::from(a.as_ref()).ok_or(())?;
     let b = Version::from(b.as_ref()).ok_or(())?;
     Ok(a.compare(b))
 }
 
-/// Compare two version number strings to each other and test against the given comparison
-/// `operator`.
+/// Get the relation of two version number strings, as single most specific operator.
+///
+/// This is the same as `compare`, and is always one of `Cmp::Eq`, `Cmp::Lt` or `Cmp::Gt`. Less
+/// specific operators such as `Cmp::Le` and `Cmp::Ne` also hold for a relation, but are never
+/// returned. Use `compare_to` to test against any operator, or `Version::relations` to get all
+/// operators that hold.
 ///
 /// If either version number string is invalid an error is returned.
 ///
 /// # Examples
 ///
 /// ```
-/// use version_compare::{Cmp, compare_to};
+/// use version_compare::{Cmp, compare_to, relation};
 ///
-/// assert!(compare_to("1.2.3", "1.2.3", Cmp::Eq).unwrap());
-/// assert!(compare_to("1.2.3", "1.2.3", Cmp::Le).unwrap());
-/// assert!(compare_to("1.2.3", "1.2.4", Cmp::Lt).unwrap());
-/// assert!(compare_to("1", "0.1", Cmp::Gt).unwrap());
-/// assert!(compare_to("1", "0.1", Cmp::Ge).unwrap());
+/// assert_eq!(relation("1.2", "1.3"), Ok(Cmp::Lt));
+/// assert_eq!(relation("1.2", "1.2.0"), Ok(Cmp::Eq));
+///
+/// // Not equal holds, but is never the relation
+/// assert_ne!(relation("1.2", "1.3"), Ok(Cmp::Ne));
+/// assert_eq!(compare_to("1.2", "1.3", Cmp::Ne), Ok(true));
 /// ```
 #[allow(clippy::result_unit_err)]
-pub fn compare_to<A, B>(a: A, b: B, operator: Cmp) -> Result<bool, ()>
+pub fn relation<A, B>(a: A, b: B) -> Result<Cmp, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    compare(a, b)
+}
+
+/// Check whether two version number strings are not equal.
+///
+/// This is the same as `compare_to` with `Cmp::Ne`. Versions only differing in trailing zeros
+/// are equal.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::is_ne;
+///
+/// assert_eq!(is_ne("1.2", "1.3"), Ok(true));
+/// assert_eq!(is_ne("1.2", "1.2.0"), Ok(false));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn is_ne<A, B>(a: A, b: B) -> Result<bool, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    Ok(compare(a, b)? != Cmp::Eq)
+}
+
+/// Compare two purely numeric dotted version number strings, such as `1.2.3`, without parsing.
+///
+/// This is a fast path for the most common versions, giving the same result as `compare`.
+/// `None` is returned if either version string is not purely numeric, to fall back to parsing.
+fn compare_numeric(a: &str, b: &str) -> Option<Cmp> {
+    if !is_numeric(a) || !is_numeric(b) {
+        return None;
+    }
+
+    // Compare numbers pairwise, a missing number equals zero
+    let (mut a, mut b) = (a.split('.'), b.split('.'));
+    loop {
+        let (lhs, rhs) = match (a.next(), b.next()) {
+            (None, None) => return Some(Cmp::Eq),
+            (lhs, rhs) => (lhs.map_or(0, parse_digits), rhs.map_or(0, parse_digits)),
+        };
+        match lhs.cmp(&rhs) {
+            Ordering::Equal => {}
+            ord => return Some(ord.into()),
+        }
+    }
+}
+
+/// Check whether the given version string only has dot separated numbers fitting in a part.
+fn is_numeric(version: &str) -> bool {
+    version
+        .split('.')
+        .all(|n| (1..=9).contains(&n.len()) && n.bytes().all(|c| c.is_ascii_digit()))
+}
+
+/// Parse a string of at most 9 digits, which always fits.
+fn parse_digits(digits: &str) -> u32 {
+    digits.bytes().fold(0, |n, c| n * 10 + u32::from(c - b'0'))
+}
+
+/// Compare two version number strings to each other, ignoring the part at the given index.
+///
+/// This is the same as `compare`, but the part at `ignore_index` of both versions is compared
+/// as zero. Parts are numbered from zero, so index `2` ignores the patch number of a
+/// `major.minor.patch` version while still comparing the parts after it.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, compare_ignoring};
+///
+/// assert_eq!(compare_ignoring("1.2.9", "1.2.0", 2), Ok(Cmp::Eq));
+/// assert_eq!(compare_ignoring("1.3.9", "1.2.0", 2), Ok(Cmp::Gt));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_ignoring<A, B>(a: A, b: B, ignore_index: usize) -> Result<Cmp, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    let a = parse_ignoring(a.as_ref(), ignore_index).ok_or(())?;
+    let b = parse_ignoring(b.as_ref(), ignore_index).ok_or(())?;
+    Ok(a.compare(b))
+}
+
+/// Parse the given version string, replacing the part at `ignore_index` with zero.
+fn parse_ignoring(version: &str, ignore_index: usize) -> Option<Version<'_>> {
+    let mut parts = Version::from(version)?.parts().to_vec();
+    if let Some(part) = parts.get_mut(ignore_index) {
+        *part = Part::Number(0);
+    }
+    Some(Version::from_parts(version, parts))
+}
+
+/// Compare two version number strings to each other, where missing trailing parts match anything.
+///
+/// This is the same as `compare`, but only compares the parts both versions have, so the shorter
+/// version acts as a prefix. Unlike the trailing zero rule of `compare`, `1.2` equals `1.2.9` and
+/// `1.2.rc1`. Parts within the shared prefix are still compared, so `1.3` is greater than `1.2.9`.
+/// A trailing `build N` segment isn't part of the prefix, it only breaks ties like in `compare`.
+/// This is useful to match a version against a partial version, such as a release line.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, compare_prefix};
+///
+/// assert_eq!(compare_prefix("1.2", "1.2.9"), Ok(Cmp::Eq));
+/// assert_eq!(compare_prefix("1.3", "1.2.9"), Ok(Cmp::Gt));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_prefix<A, B>(a: A, b: B) -> Result<Cmp, ()>
 where
     A: AsRef<str>,
     B: AsRef<str>,
 {
     let a = Version::from(a.as_ref()).ok_or(())?;
     let b = Version::from(b.as_ref()).ok_or(())?;
-    Ok(a.compare_to(b, operator))
+    Ok(a.compare_prefix(&b))
 }
 
-#[cfg_attr(tarpaulin, skip)]
-#[cfg(test)]
-mod tests {
-    use crate::test::{COMBIS, COMBIS_ERROR};
-    use crate::Cmp;
+/// Compare two version number strings to each other, ignoring any epoch.
+///
+/// This is the same as `compare`, but strips a leading epoch from both versions before comparing,
+/// being a number followed by `!` as in PEP 440 (`1!2.0`) or `:` as in Debian and Arch Linux
+/// (`1:2.0`). This is useful to detect the same upstream version with a different packaging
+/// epoch.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, compare_ignore_epoch};
+///
+/// assert_eq!(compare_ignore_epoch("1!2.0", "2.0"), Ok(Cmp::Eq));
+/// assert_eq!(compare_ignore_epoch("2:1.0", "1:1.1"), Ok(Cmp::Lt));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_ignore_epoch<A, B>(a: A, b: B) -> Result<Cmp, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    compare(strip_epoch(a.as_ref()), strip_epoch(b.as_ref()))
+}
 
-    #[test]
-    fn compare() {
-        // Compare each version in the version set having the default manifest
-        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
-            assert_eq!(
-                super::compare(entry.0, entry.1),
-                Ok(entry.2),
-                "Testing that {} is {} {}",
-                entry.0,
-                entry.2.sign(),
-                entry.1,
-            );
-        }
+/// Strip a leading epoch, being a number followed by `!` or `:`, from the given version string.
+fn strip_epoch(version: &str) -> &str {
+    let digits = version.bytes().take_while(u8::is_ascii_digit).count();
+    match version[digits..].strip_prefix(|c| c == '!' || c == ':') {
+        Some(rest) if digits > 0 => rest,
+        _ => version,
+    }
+}
 
-        // Compare each error version in the version set
-        for entry in COMBIS_ERROR {
-            let result = super::compare(entry.0, entry.1);
+/// Compare two version number strings to each other, and explain the result.
+///
+/// This is the same as `compare`, but also returns a human readable reason for the result, such
+/// as `differ at part 2: 3 < 9`. This is useful for tools showing users why one version is newer
+/// than another. Parts are numbered from zero, the wording of reasons may change.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, compare_explain};
+///
+/// assert_eq!(
+///     compare_explain("1.2.3", "1.2.9"),
+///     Ok((Cmp::Lt, "differ at part 2: 3 < 9".into())),
+/// );
+/// assert_eq!(
+///     compare_explain("1.2", "1.2.0"),
+///     Ok((Cmp::Eq, "a is shorter; trailing zeros equal".into())),
+/// );
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_explain<A, B>(a: A, b: B) -> Result<(Cmp, String), ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    let a = Version::from(a.as_ref()).ok_or(())?;
+    let b = Version::from(b.as_ref()).ok_or(())?;
+    let cmp = a.compare(&b);
+    let (lhs, rhs) = (a.parts(), b.parts());
+    let index = a.common_prefix_len(&b);
 
-            if result.is_ok() {
-                assert!(result != Ok(entry.2));
-            }
+    let reason = match cmp {
+        Cmp::Eq => match lhs.len().cmp(&rhs.len()) {
+            Ordering::Less => "a is shorter; trailing zeros equal".into(),
+            Ordering::Greater => "b is shorter; trailing zeros equal".into(),
+            Ordering::Equal => "all parts equal".into(),
+        },
+        _ if index < lhs.len().max(rhs.len()) => {
+            let part = |parts: &[Part]| parts.get(index).map_or("missing".into(), Part::to_string);
+            format!(
+                "differ at part {}: {} {} {}",
+                index,
+                part(lhs),
+                cmp.sign(),
+                part(rhs),
+            )
         }
+        _ => "all parts equal; differ in part order".into(),
+    };
+
+    Ok((cmp, reason))
+}
+
+/// Compare two version number strings to each other, returning the parsed versions as well.
+///
+/// This is the same as `compare`, but also returns both parsed versions. This avoids parsing
+/// twice when the versions are inspected after comparing.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, Part, compare_parsed};
+///
+/// let (cmp, a, b) = compare_parsed("1.2.3", "1.2.4-rc1").unwrap();
+///
+/// assert_eq!(cmp, Cmp::Lt);
+/// assert_eq!(a.as_str(), "1.2.3");
+/// assert_eq!(b.part(3), Ok(Part::Text("rc1")));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_parsed<'a>(a: &'a str, b: &'a str) -> Result<(Cmp, Version<'a>, Version<'a>), ()> {
+    let a = Version::from(a).ok_or(())?;
+    let b = Version::from(b).ok_or(())?;
+    Ok((a.compare(&b), a, b))
+}
+
+/// Compare two optional version number strings to each other, with `None` being the lowest.
+///
+/// This is the same as `compare`, but a missing version is less than any version and equal to
+/// another missing version. This is useful to compare optional versions uniformly, such as the
+/// installed version of a package that may not be installed.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, compare_opt};
+///
+/// assert_eq!(compare_opt(None, Some("0.0.0")), Ok(Cmp::Lt));
+/// assert_eq!(compare_opt(None, None), Ok(Cmp::Eq));
+/// assert_eq!(compare_opt(Some("1.2"), Some("1.10")), Ok(Cmp::Lt));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_opt(a: Option<&str>, b: Option<&str>) -> Result<Cmp, ()> {
+    match (a, b) {
+        (Some(a), Some(b)) => compare(a, b),
+        (Some(a), None) => Version::from(a).map(|_| Cmp::Gt).ok_or(()),
+        (None, Some(b)) => Version::from(b).map(|_| Cmp::Lt).ok_or(()),
+        (None, None) => Ok(Cmp::Eq),
     }
+}
 
-    #[test]
-    fn compare_to() {
-        // Compare each version in the version set having the default manifest
-        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
-            // Test
-            assert!(super::compare_to(entry.0, entry.1, entry.2).unwrap());
+/// Pick the newer of two version number strings.
+///
+/// This compares version `a` to version `b`, and returns whichever is greater. If both versions
+/// are equal, `a` is returned.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::newer;
+///
+/// assert_eq!(newer("1.2", "1.10"), Ok("1.10"));
+/// assert_eq!(newer("1.2", "1.2.0"), Ok("1.2"));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn newer<'a>(a: &'a str, b: &'a str) -> Result<&'a str, ()> {
+    match compare(a, b)? {
+        Cmp::Lt => Ok(b),
+        _ => Ok(a),
+    }
+}
 
-            // Make sure the inverse operator is not correct
-            assert!(!super::compare_to(entry.0, entry.1, entry.2.invert()).unwrap());
+/// Find the closest candidate version not exceeding the `target` version.
+///
+/// This returns the greatest candidate that is less than or equal to `target`, the best
+/// available version for a requested version. If multiple candidates are equal, the first one is
+/// returned. Invalid candidate version strings are skipped.
+///
+/// `None` is returned if no candidate is less than or equal to `target`, or if `target` is an
+/// invalid version string.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::closest;
+///
+/// assert_eq!(closest("1.5", &["1.2", "1.4", "1.6"]), Some("1.4"));
+/// assert_eq!(closest("1.6.0", &["1.2", "1.4", "1.6"]), Some("1.6"));
+/// assert_eq!(closest("1.0", &["1.2", "1.4", "1.6"]), None);
+/// ```
+pub fn closest<'a>(target: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let target = Version::from(target)?;
+    let mut closest: Option<(&'a str, Version)> = None;
+
+    for candidate in candidates {
+        let version = match Version::from(candidate) {
+            Some(version) if version <= target => version,
+            _ => continue,
+        };
+        if closest.as_ref().map_or(true, |(_, best)| &version > best) {
+            closest = Some((candidate, version));
         }
+    }
 
-        // Compare each error version in the version set
-        for entry in COMBIS_ERROR {
-            let result = super::compare_to(entry.0, entry.1, entry.2);
+    closest.map(|(candidate, _)| candidate)
+}
 
-            if result.is_ok() {
-                assert!(!result.unwrap())
-            }
-        }
+/// Compare two version number strings to each other, permissively.
+///
+/// This is the same as `compare`, accepting messy version strings with text parts and
+/// overflowing numbers. If a `max_depth` is given, parts beyond it are ignored. See
+/// `compare_strict` for the strict counterpart.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, compare_loose};
+///
+/// assert_eq!(compare_loose("1.2.3-rc1", "1.2.3", None), Ok(Cmp::Lt));
+/// assert_eq!(compare_loose("1.2.3", "1.2.4", Some(2)), Ok(Cmp::Eq));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_loose<A, B>(a: A, b: B, max_depth: Option<usize>) -> Result<Cmp, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    let mut manifest = Manifest::builder().build();
+    manifest.max_depth = max_depth;
+    compare_manifest(a.as_ref(), b.as_ref(), &manifest)
+}
 
-        // Assert an exceptional case, compare to not equal
-        assert!(super::compare_to("1.2.3", "1.2", Cmp::Ne).unwrap());
+/// Compare two version number strings to each other, strictly.
+///
+/// This is the same as `compare_loose`, but rejects version strings having text parts,
+/// overflowing numbers, or more parts than the given `max_depth`, as described in
+/// `Manifest::strict`.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, compare_strict};
+///
+/// assert_eq!(compare_strict("1.2.3", "1.2.4", None), Ok(Cmp::Lt));
+/// assert!(compare_strict("1.2.3-rc1", "1.2.3", None).is_err());
+/// assert!(compare_strict("1.2.3", "1.2.4", Some(2)).is_err());
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_strict<A, B>(a: A, b: B, max_depth: Option<usize>) -> Result<Cmp, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    let mut manifest = Manifest::builder().strict(true).build();
+    manifest.max_depth = max_depth;
+    compare_manifest(a.as_ref(), b.as_ref(), &manifest)
+}
+
+/// Compare two version number strings to each other, parsed with the given `manifest`.
+fn compare_manifest(a: &str, b: &str, manifest: &Manifest) -> Result<Cmp, ()> {
+    let a = Version::from_manifest(a, manifest).ok_or(())?;
+    let b = Version::from_manifest(b, manifest).ok_or(())?;
+    Ok(a.compare(b))
+}
+
+/// Compare two version number strings to each other, returning Rusts `Ordering`.
+///
+/// This is the same as `compare`, but returns `std::cmp::Ordering` rather than `Cmp`.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use version_compare::cmp;
+///
+/// assert_eq!(cmp("1.2", "1.3"), Ok(Ordering::Less));
+/// assert_eq!(cmp("1.2", "1.2.0"), Ok(Ordering::Equal));
+/// assert_eq!(cmp("1", "0.1"), Ok(Ordering::Greater));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn cmp<A, B>(a: A, b: B) -> Result<Ordering, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    Ok(compare(a, b)?.ord().unwrap())
+}
+
+/// Compare two version number strings to each other and test against the given comparison
+/// `operator`.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, compare_to};
+///
+/// assert!(compare_to("1.2.3", "1.2.3", Cmp::Eq).unwrap());
+/// assert!(compare_to("1.2.3", "1.2.3", Cmp::Le).unwrap());
+/// assert!(compare_to("1.2.3", "1.2.4", Cmp::Lt).unwrap());
+/// assert!(compare_to("1", "0.1", Cmp::Gt).unwrap());
+/// assert!(compare_to("1", "0.1", Cmp::Ge).unwrap());
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_to<A, B>(a: A, b: B, operator: Cmp) -> Result<bool, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    let a = Version::from(a.as_ref()).ok_or(())?;
+    let b = Version::from(b.as_ref()).ok_or(())?;
+    Ok(a.compare_to(b, operator))
+}
+
+/// Check two version number strings against the given comparison `operator`, returning the
+/// actual relation if it doesn't hold.
+///
+/// This is the same as `compare_to`, but returns `Ok(Err(cmp))` with the actual relation `cmp` of
+/// version `a` to version `b` when the operator doesn't hold. This is useful to report why a
+/// requirement failed, such as `1.2 is Lt 1.3` in dependency checks. The actual relation is
+/// always `Cmp::Eq`, `Cmp::Lt` or `Cmp::Gt`.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, check};
+///
+/// assert_eq!(check("1.3", "1.2", Cmp::Ge), Ok(Ok(())));
+/// assert_eq!(check("1.2", "1.3", Cmp::Ge), Ok(Err(Cmp::Lt)));
+/// assert_eq!(check("1.2", "1.2.0", Cmp::Ne), Ok(Err(Cmp::Eq)));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn check<A, B>(a: A, b: B, operator: Cmp) -> Result<Result<(), Cmp>, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    let cmp = compare(a, b)?;
+    if satisfies(cmp, operator) {
+        Ok(Ok(()))
+    } else {
+        Ok(Err(cmp))
+    }
+}
+
+/// Check whether two version number strings are within the given `tolerance` of each other at
+/// the given part `position`.
+///
+/// All parts before the zero-based `position` must be equal, and the numbers at `position` may
+/// differ by at most `tolerance`, inclusive. Parts after `position` are ignored. Missing parts are
+/// zero, and text parts at `position` must be equal. Parts are equal if they compare equal like
+/// with `compare`, so `rc` equals `RC`. This is useful to check whether versions are
+/// roughly the same, such as within a few patch releases with `position` 2.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::within;
+///
+/// assert_eq!(within("1.2.3", "1.2.5", 2, 3), Ok(true));
+/// assert_eq!(within("1.2.3", "1.2.7", 2, 3), Ok(false));
+/// assert_eq!(within("1.2.3", "1.3.3", 2, 3), Ok(false));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn within<A, B>(a: A, b: B, position: usize, tolerance: u32) -> Result<bool, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    let a = Version::from(a.as_ref()).ok_or(())?;
+    let b = Version::from(b.as_ref()).ok_or(())?;
+
+    // Parts are equal up to the common prefix, or all parts if it covers the longest version
+    let prefix = match a.common_prefix_len(&b) {
+        len if len == a.parts().len().max(b.parts().len()) => usize::MAX,
+        len => len,
+    };
+    if prefix < position {
+        return Ok(false);
+    }
+
+    Ok(
+        match (a.saturating_part(position), b.saturating_part(position)) {
+            (Part::Number(a), Part::Number(b)) => {
+                (i64::from(a) - i64::from(b)).unsigned_abs() <= u64::from(tolerance)
+            }
+            _ => prefix > position,
+        },
+    )
+}
+
+/// Compare two version number strings to each other following the given version `scheme`.
+///
+/// This is the scheme aware variant of `compare`, see `Scheme` for the available schemes.
+///
+/// If either version number string is invalid for the scheme an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, Scheme, compare_scheme};
+///
+/// assert_eq!(compare_scheme("1!1.0", "2.0", Scheme::Generic), Ok(Cmp::Lt));
+/// assert_eq!(compare_scheme("1!1.0", "2.0", Scheme::Pep440), Ok(Cmp::Gt));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_scheme<A, B>(a: A, b: B, scheme: Scheme) -> Result<Cmp, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    scheme.compare(a, b)
+}
+
+/// Compare two version number strings like PHP's `version_compare`, and test against the given
+/// comparison `operator`.
+///
+/// This reproduces PHP's algorithm exactly, including the ordering of its special forms, see
+/// `Scheme::Php`. Unlike `Scheme::Php`, a version ending with a separator is less than itself,
+/// as in PHP. Like in PHP, any version string is valid, so no error is returned. PHP's
+/// operator strings such as `lt` and `<>` can be parsed with `Cmp::from_name` and
+/// `Cmp::from_sign`.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, php_compare};
+///
+/// assert!(php_compare("5.3.0-dev", "5.3.0", Cmp::Lt));
+/// assert!(php_compare("1.0rc1", "1.0RC1", Cmp::Eq));
+/// assert!(php_compare("1.0", "1.0pl1", Cmp::from_name("lt").unwrap()));
+/// ```
+pub fn php_compare<A, B>(a: A, b: B, operator: Cmp) -> bool
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    crate::scheme::php::compare(a.as_ref(), b.as_ref())
+        .map_or(false, |cmp| satisfies(cmp, operator))
+}
+
+/// Compare two version number strings to each other with the given `options`.
+///
+/// This is the same as `compare`, but parses and compares the versions following the manifest
+/// and scheme in `options`. If a scheme other than `Scheme::Generic` is set, the versions are
+/// compared as in `compare_scheme` and the manifest is not used.
+///
+/// If either version number string is invalid an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{compare_opts, Cmp, CompareOptions, Manifest, Scheme};
+///
+/// let options = CompareOptions::default().manifest(Manifest::builder().max_depth(2).build());
+/// assert_eq!(compare_opts("1.2.3", "1.2.4", &options), Ok(Cmp::Eq));
+///
+/// let options = CompareOptions::default().scheme(Scheme::Pep440);
+/// assert_eq!(compare_opts("1!1.0", "2.0", &options), Ok(Cmp::Gt));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn compare_opts<A, B>(a: A, b: B, options: &CompareOptions) -> Result<Cmp, ()>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+{
+    if options.scheme != Scheme::Generic {
+        return options.scheme.compare(a, b);
+    }
+
+    let a = Version::from_manifest(a.as_ref(), &options.manifest).ok_or(())?;
+    let b = Version::from_manifest(b.as_ref(), &options.manifest).ok_or(())?;
+    Ok(a.compare(b))
+}
+
+/// Get a comparator for version number strings, to use with `slice::sort_by` and friends.
+///
+/// The returned closure compares version `a` to version `b` and returns Rusts `Ordering`. This
+/// makes it easy to sort any kind of data by a version string, without parsing boilerplate.
+///
+/// Invalid version number strings are ordered before all valid ones. Two invalid version number
+/// strings are ordered by their raw string value.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::cmp_fn;
+///
+/// let mut versions = vec!["1.10", "1.2", "1.9.1"];
+/// let cmp = cmp_fn();
+/// versions.sort_by(|a, b| cmp(a, b));
+///
+/// assert_eq!(versions, ["1.2", "1.9.1", "1.10"]);
+/// ```
+pub fn cmp_fn() -> impl Fn(&str, &str) -> Ordering {
+    |a: &str, b: &str| match compare_numeric(a, b) {
+        Some(cmp) => cmp.ord().unwrap(),
+        None => cmp_parsed(a, b),
+    }
+}
+
+/// Get a comparator for version number strings with a deterministic order, to display sorted
+/// versions.
+///
+/// This is the same as `cmp_fn`, but never returns `Ordering::Equal` for differently spelled
+/// versions. Versions comparing equal, such as `1.2` and `1.2.0`, are ordered by their number of
+/// parts first, and then by their raw string value. Sorting a list with this comparator therefore
+/// gives the same order, regardless of the original order of the list.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::cmp_display_fn;
+///
+/// let mut versions = vec!["1.2.0", "1.10", "1.2", "01.2"];
+/// let cmp = cmp_display_fn();
+/// versions.sort_by(|a, b| cmp(a, b));
+///
+/// assert_eq!(versions, ["01.2", "1.2", "1.2.0", "1.10"]);
+/// ```
+pub fn cmp_display_fn() -> impl Fn(&str, &str) -> Ordering {
+    let cmp = cmp_fn();
+    move |a: &str, b: &str| {
+        cmp(a, b).then_with(|| {
+            let count = |version| Version::from(version).map_or(0, |v| v.parts().len());
+            count(a).cmp(&count(b)).then_with(|| a.cmp(b))
+        })
+    }
+}
+
+/// Compare two version number strings by parsing them, as in `cmp_fn`.
+fn cmp_parsed(a: &str, b: &str) -> Ordering {
+    match (Version::from(a), Version::from(b)) {
+        (Some(a), Some(b)) => a.compare(b).ord().unwrap(),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => a.cmp(b),
+    }
+}
+
+/// Sort a list of version number strings in ascending order, reporting invalid entries.
+///
+/// The sorted list is returned if all version number strings are valid. Versions comparing
+/// equal keep their original order. Otherwise an error is returned listing all invalid version
+/// number strings, in their original order.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::try_sort;
+///
+/// assert_eq!(try_sort(&["1.10", "1.2", "1.9.1"]), Ok(vec!["1.2", "1.9.1", "1.10"]));
+/// assert_eq!(try_sort(&["1.10", "abc", "1.2"]), Err(vec!["abc"]));
+/// ```
+pub fn try_sort<'a>(versions: &[&'a str]) -> Result<Vec<&'a str>, Vec<&'a str>> {
+    let parsed: Vec<(&'a str, Option<Version>)> = versions
+        .iter()
+        .map(|version| (*version, Version::from(version)))
+        .collect();
+
+    // Report all invalid versions
+    let invalid: Vec<&'a str> = parsed
+        .iter()
+        .filter(|(_, parsed)| parsed.is_none())
+        .map(|(version, _)| *version)
+        .collect();
+    if !invalid.is_empty() {
+        return Err(invalid);
+    }
+
+    let mut sorted: Vec<(&'a str, Version)> = parsed
+        .into_iter()
+        .map(|(version, parsed)| (version, parsed.unwrap()))
+        .collect();
+    sorted.sort_by(|(_, a), (_, b)| a.compare(b).ord().unwrap());
+    Ok(sorted.into_iter().map(|(version, _)| version).collect())
+}
+
+/// Merge two sorted lists of version number strings into a single sorted list.
+///
+/// Both lists must already be sorted in ascending order, as with `cmp_fn`. The lists are merged
+/// in a single pass, without re-sorting. Invalid version number strings are ordered as in
+/// `cmp_fn`.
+///
+/// Versions comparing equal are deduplicated, keeping the first one. Versions from `a` come
+/// before equal versions from `b`.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::merge_sorted;
+///
+/// let merged = merge_sorted(&["1.0", "1.2", "2.0"], &["1.0.0", "1.5"]);
+///
+/// assert_eq!(merged, ["1.0", "1.2", "1.5", "2.0"]);
+/// ```
+pub fn merge_sorted<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<&'a str> {
+    let cmp = cmp_fn();
+    let mut merged: Vec<&'a str> = Vec::with_capacity(a.len() + b.len());
+    let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+
+    loop {
+        // Take the lowest version of both lists, preferring the first list
+        let next = match (a.peek(), b.peek()) {
+            (Some(lhs), Some(rhs)) if cmp(lhs, rhs) == Ordering::Greater => b.next(),
+            (Some(_), _) => a.next(),
+            (None, Some(_)) => b.next(),
+            (None, None) => break,
+        };
+        let next = *next.unwrap();
+
+        // Skip versions equal to the last one
+        if merged
+            .last()
+            .map_or(true, |last| cmp(last, next) != Ordering::Equal)
+        {
+            merged.push(next);
+        }
+    }
+
+    merged
+}
+
+/// Remove duplicate version number strings, keeping the first occurrence.
+///
+/// Versions are duplicates if they compare equal, even if spelled differently, such as `1.2` and
+/// `1.2.0`. The list doesn't have to be sorted, and the order of kept versions is preserved.
+/// Invalid version number strings are only duplicates if their raw string values are equal.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::dedup;
+///
+/// let versions = dedup(&["1.2", "1.2.0", "1.2.0.0", "1.3"]);
+///
+/// assert_eq!(versions, ["1.2", "1.3"]);
+/// ```
+pub fn dedup<'a>(versions: &[&'a str]) -> Vec<&'a str> {
+    // Parse each version once, invalid versions are compared by their raw string
+    let parsed: Vec<Option<Version>> = versions.iter().map(|v| Version::from(v)).collect();
+    let cmp = |a: usize, b: usize| match (&parsed[a], &parsed[b]) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (None, None) => versions[a].cmp(versions[b]),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    };
+
+    // Stable sort, so the first occurrence leads each run of equal versions
+    let mut order: Vec<usize> = (0..versions.len()).collect();
+    order.sort_by(|&a, &b| cmp(a, b));
+
+    let mut keep = vec![false; versions.len()];
+    for (i, &index) in order.iter().enumerate() {
+        keep[index] = i == 0 || cmp(order[i - 1], index) != Ordering::Equal;
+    }
+
+    versions
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|(version, _)| *version)
+        .collect()
+}
+
+/// Group a list of version number strings by their `major.minor` release line.
+///
+/// Each group is keyed by the major and minor number, and has its versions sorted in ascending
+/// order. Versions comparing equal keep their original order. A missing or non-numeric minor
+/// number is zero, so `2` and `2-rc1` are in the `2.0` line. This is useful for release
+/// dashboards, to show the latest patch release of each release line.
+///
+/// Invalid version number strings and versions not starting with a number are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::group_by_minor;
+///
+/// let lines = group_by_minor(&["1.2.3", "2.0.1", "1.2.0", "1.3.0"]);
+///
+/// assert_eq!(lines[&(1, 2)], ["1.2.0", "1.2.3"]);
+/// assert_eq!(lines.keys().collect::<Vec<_>>(), [&(1, 2), &(1, 3), &(2, 0)]);
+/// ```
+pub fn group_by_minor<'a>(versions: &[&'a str]) -> BTreeMap<(u64, u64), Vec<&'a str>> {
+    let mut lines: BTreeMap<(u64, u64), Vec<&'a str>> = BTreeMap::new();
+    for version in versions {
+        let segments = match Version::from(version) {
+            Some(parsed) => parsed.segments_numeric(),
+            None => continue,
+        };
+        let major = match segments.first() {
+            Some(Some(major)) => *major,
+            _ => continue,
+        };
+        let minor = segments.get(1).copied().flatten().unwrap_or(0);
+        lines.entry((major, minor)).or_default().push(version);
+    }
+
+    let cmp = cmp_fn();
+    for line in lines.values_mut() {
+        line.sort_by(|a, b| cmp(a, b));
+    }
+    lines
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::test::{Rng, COMBIS, COMBIS_ERROR};
+    use crate::version::satisfies;
+    use crate::{Cmp, CompareOptions, Manifest, OverflowPolicy, Scheme, Version};
+
+    #[test]
+    fn compare() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(
+                super::compare(entry.0, entry.1),
+                Ok(entry.2),
+                "Testing that {} is {} {}",
+                entry.0,
+                entry.2.sign(),
+                entry.1,
+            );
+        }
+
+        // Compare each error version in the version set
+        for entry in COMBIS_ERROR {
+            let result = super::compare(entry.0, entry.1);
+
+            if result.is_ok() {
+                assert!(result != Ok(entry.2));
+            }
+        }
+    }
+
+    #[test]
+    fn relation() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            let relation = super::relation(entry.0, entry.1).unwrap();
+            assert_eq!(relation, entry.2);
+            assert_ne!(relation, Cmp::Ne);
+            assert_eq!(
+                super::is_ne(entry.0, entry.1),
+                super::compare_to(entry.0, entry.1, Cmp::Ne),
+            );
+        }
+
+        assert_eq!(super::is_ne("1.2", "1.2.1"), Ok(true));
+        assert_eq!(super::is_ne("1.2", "1.2.0"), Ok(false));
+        assert_eq!(super::is_ne("1.2", "abc"), Err(()));
+        assert_eq!(super::relation("1.2", "abc"), Err(()));
+    }
+
+    #[test]
+    fn compare_numeric() {
+        // The fast path must match parsing for all numeric versions in the version set
+        let mut count = 0;
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            if let Some(cmp) = super::compare_numeric(entry.0, entry.1) {
+                assert_eq!(
+                    cmp,
+                    entry.2,
+                    "Testing that {} is {} {}",
+                    entry.0,
+                    entry.2.sign(),
+                    entry.1
+                );
+                count += 1;
+            }
+        }
+        assert!(count > 0);
+
+        // The fast path must match parsing for random numeric versions
+        let mut rng = Rng::new(7);
+        for _ in 0..2000 {
+            let (a, b) = (rng.version(), rng.version());
+            if let Some(cmp) = super::compare_numeric(&a, &b) {
+                let parsed = Version::from(&a)
+                    .unwrap()
+                    .compare(Version::from(&b).unwrap());
+                assert_eq!(cmp, parsed, "Testing that {} is {} {}", a, parsed.sign(), b);
+            }
+        }
+        for (a, b) in [("1.2.3", "1.2.4"), ("01.2", "1.2.0"), ("999999999", "1.0")] {
+            assert_eq!(
+                super::compare_numeric(a, b),
+                Some(Version::from(a).unwrap().compare(Version::from(b).unwrap())),
+            );
+        }
+
+        // Other versions fall back to parsing
+        for (a, b) in [
+            ("1.2.3", "1.2.3-rc1"),
+            ("v1.2", "1.2"),
+            ("1..2", "1.2"),
+            ("1.2.", "1.2"),
+            ("", "1.2"),
+            ("1.2147483648", "1.2"),
+            ("1-2", "1.2"),
+        ] {
+            assert_eq!(super::compare_numeric(a, b), None);
+            assert_eq!(super::compare_numeric(b, a), None);
+        }
+    }
+
+    #[test]
+    fn compare_ignoring() {
+        for (a, b, index, cmp) in [
+            ("1.2.9", "1.2.0", 2, Cmp::Eq),
+            ("1.2.9", "1.2", 2, Cmp::Eq),
+            ("1.2.0", "1.2.9", 2, Cmp::Eq),
+            ("1.2.9", "1.2.0", 1, Cmp::Gt),
+            ("1.2.9", "1.2.0", 3, Cmp::Gt),
+            ("1.2.0", "1.2.9", 0, Cmp::Lt),
+            ("1.3.0", "1.2.9", 2, Cmp::Gt),
+            ("1.2.9.1", "1.2.0.2", 2, Cmp::Lt),
+            ("1.2.rc1", "1.2", 2, Cmp::Eq),
+        ] {
+            assert_eq!(
+                super::compare_ignoring(a, b, index),
+                Ok(cmp),
+                "{} vs {}",
+                a,
+                b
+            );
+        }
+
+        // Ignoring a part beyond all parts is the same as comparing
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(super::compare_ignoring(entry.0, entry.1, 100), Ok(entry.2));
+        }
+
+        assert!(super::compare_ignoring("abc", "1.2", 0).is_err());
+    }
+
+    #[test]
+    fn compare_ignore_epoch() {
+        // Epochs compare as regular parts normally
+        assert_eq!(super::compare("1!1.0", "2.0"), Ok(Cmp::Lt));
+        assert_eq!(super::compare("1!2.0", "2.0"), Ok(Cmp::Lt));
+
+        for (a, b, cmp) in [
+            ("1!1.0", "2.0", Cmp::Lt),
+            ("1!2.0", "2.0", Cmp::Eq),
+            ("1!2.0", "3!2.0.0", Cmp::Eq),
+            ("1:2.0-1", "2.0-1", Cmp::Eq),
+            ("2:1.0", "1:1.1", Cmp::Lt),
+            ("1!2.1", "2.0", Cmp::Gt),
+        ] {
+            assert_eq!(super::compare_ignore_epoch(a, b), Ok(cmp), "{} vs {}", a, b);
+        }
+
+        // Only a leading number followed by an epoch separator is stripped
+        assert_eq!(super::strip_epoch("12!1.0"), "1.0");
+        assert_eq!(super::strip_epoch("!1.0"), "!1.0");
+        assert_eq!(super::strip_epoch("v1:1.0"), "v1:1.0");
+        assert_eq!(super::strip_epoch("1.0:1"), "1.0:1");
+
+        // Versions without epoch compare normally
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            if super::strip_epoch(entry.0) == entry.0 && super::strip_epoch(entry.1) == entry.1 {
+                assert_eq!(super::compare_ignore_epoch(entry.0, entry.1), Ok(entry.2));
+            }
+        }
+
+        assert!(super::compare_ignore_epoch("1!abc", "1.2").is_err());
+    }
+
+    #[test]
+    fn compare_explain() {
+        // The explained result must match comparing for each version in the version set
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            let (cmp, reason) = super::compare_explain(entry.0, entry.1).unwrap();
+            assert_eq!(cmp, entry.2);
+            assert!(!reason.is_empty());
+        }
+
+        for (a, b, cmp, reason) in [
+            ("1.2.3", "1.2.9", Cmp::Lt, "differ at part 2: 3 < 9"),
+            ("2", "1.9", Cmp::Gt, "differ at part 0: 2 > 1"),
+            ("1.2.3", "1.2.3", Cmp::Eq, "all parts equal"),
+            (
+                "1.2",
+                "1.2.0.0",
+                Cmp::Eq,
+                "a is shorter; trailing zeros equal",
+            ),
+            (
+                "1.2.0",
+                "1.2",
+                Cmp::Eq,
+                "b is shorter; trailing zeros equal",
+            ),
+            ("1.2", "1.2.1", Cmp::Lt, "differ at part 2: missing < 1"),
+            ("1.2-rc1", "1.2", Cmp::Lt, "differ at part 2: rc1 < missing"),
+            (
+                "1.2-alpha",
+                "1.2-beta",
+                Cmp::Lt,
+                "differ at part 2: alpha < beta",
+            ),
+        ] {
+            assert_eq!(super::compare_explain(a, b), Ok((cmp, reason.into())));
+        }
+
+        assert!(super::compare_explain("abc", "1.2").is_err());
+    }
+
+    #[test]
+    fn compare_parsed() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            let (cmp, a, b) = super::compare_parsed(entry.0, entry.1).unwrap();
+            assert_eq!(Ok(cmp), super::compare(entry.0, entry.1));
+            assert_eq!(cmp, entry.2);
+            assert_eq!(a.as_str(), entry.0);
+            assert_eq!(b.as_str(), entry.1);
+            assert_eq!(a, Version::from(entry.0).unwrap());
+        }
+
+        // Compare each error version in the version set
+        for entry in COMBIS_ERROR {
+            assert_eq!(
+                super::compare_parsed(entry.0, entry.1).map(|(cmp, _, _)| cmp),
+                super::compare(entry.0, entry.1),
+            );
+        }
+    }
+
+    #[test]
+    fn try_sort() {
+        assert_eq!(
+            super::try_sort(&["1.10", "1.2", "1.9.1", "1.2.0", "1.2-rc1"]),
+            Ok(vec!["1.2-rc1", "1.2", "1.2.0", "1.9.1", "1.10"]),
+        );
+        assert_eq!(super::try_sort(&[]), Ok(vec![]));
+
+        // Invalid versions are reported in order
+        assert_eq!(super::try_sort(&["1.10", "abc", "1.2"]), Err(vec!["abc"]));
+        assert_eq!(
+            super::try_sort(&["xyz", "1.0", "abc"]),
+            Err(vec!["xyz", "abc"]),
+        );
+
+        // Sorting matches the version set
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            let sorted = super::try_sort(&[entry.0, entry.1]).unwrap();
+            let expected = match entry.2 {
+                Cmp::Gt => [entry.1, entry.0],
+                _ => [entry.0, entry.1],
+            };
+            assert_eq!(sorted, expected);
+        }
+    }
+
+    #[test]
+    fn compare_opt() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(
+                super::compare_opt(Some(entry.0), Some(entry.1)),
+                Ok(entry.2)
+            );
+            assert_eq!(super::compare_opt(None, Some(entry.1)), Ok(Cmp::Lt));
+            assert_eq!(super::compare_opt(Some(entry.0), None), Ok(Cmp::Gt));
+        }
+
+        assert_eq!(super::compare_opt(None, Some("0.0.0")), Ok(Cmp::Lt));
+        assert_eq!(super::compare_opt(Some("0"), None), Ok(Cmp::Gt));
+        assert_eq!(super::compare_opt(None, None), Ok(Cmp::Eq));
+
+        // Invalid versions
+        assert_eq!(super::compare_opt(None, Some("abc")), Err(()));
+        assert_eq!(super::compare_opt(Some("abc"), None), Err(()));
+        assert_eq!(super::compare_opt(Some("1"), Some("abc")), Err(()));
+    }
+
+    #[test]
+    fn newer() {
+        // Pick the greater version of each version in the version set
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            let newer = if entry.2 == Cmp::Lt { entry.1 } else { entry.0 };
+            assert_eq!(super::newer(entry.0, entry.1), Ok(newer));
+        }
+
+        assert_eq!(super::newer("1.2", "1.10"), Ok("1.10"));
+        assert_eq!(super::newer("1.10", "1.2"), Ok("1.10"));
+        assert_eq!(super::newer("1.2.0", "1.2"), Ok("1.2.0"));
+        assert!(super::newer("abc", "1.2").is_err());
+        assert!(super::newer("1.2", "abc").is_err());
+    }
+
+    #[test]
+    fn closest() {
+        let candidates = ["1.2", "1.4", "1.6"];
+        assert_eq!(super::closest("1.5", &candidates), Some("1.4"));
+        assert_eq!(super::closest("1.4", &candidates), Some("1.4"));
+        assert_eq!(super::closest("9", &candidates), Some("1.6"));
+        assert_eq!(super::closest("1.1", &candidates), None);
+        assert_eq!(super::closest("1.5", &[]), None);
+
+        // The order of candidates doesn't matter, the first of equal candidates is returned
+        assert_eq!(super::closest("1.5", &["1.4", "1.6", "1.2"]), Some("1.4"));
+        assert_eq!(super::closest("1.5", &["1.4.0", "1.4"]), Some("1.4.0"));
+
+        // Invalid versions are skipped
+        assert_eq!(super::closest("1.5", &["abc", "1.2"]), Some("1.2"));
+        assert_eq!(super::closest("abc", &candidates), None);
+    }
+
+    #[test]
+    fn compare_loose_strict() {
+        // Plain versions compare the same
+        for (a, b, result) in [
+            ("1.2.3", "1.2.3", Cmp::Eq),
+            ("1.2", "1.2.0.0", Cmp::Eq),
+            ("1.2.3", "1.10", Cmp::Lt),
+            ("2", "1.9.9", Cmp::Gt),
+        ] {
+            assert_eq!(super::compare_loose(a, b, None), Ok(result));
+            assert_eq!(super::compare_strict(a, b, None), Ok(result));
+            assert_eq!(super::compare_loose(a, b, Some(4)), Ok(result));
+            assert_eq!(super::compare_strict(a, b, Some(4)), Ok(result));
+        }
+
+        // Messy versions only compare loosely, one for each reason to reject
+        for (a, b, max_depth, result) in [
+            // Stray text
+            ("1.2.3-rc1", "1.2.3", None, Cmp::Lt),
+            ("1.2.3 build 5", "1.2.3", None, Cmp::Gt),
+            // More parts than the maximum depth
+            ("1.2.3", "1.3", Some(2), Cmp::Lt),
+            ("1.2.3", "1.2.4", Some(2), Cmp::Eq),
+            // Overflowing numbers
+            ("1.123456789012345678901234567890", "1.2", None, Cmp::Gt),
+        ] {
+            assert_eq!(super::compare_loose(a, b, max_depth), Ok(result));
+            assert!(super::compare_strict(a, b, max_depth).is_err());
+            assert!(super::compare_strict(b, a, max_depth).is_err());
+        }
+
+        // Loosely comparing is the same as comparing, without a maximum depth
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(
+                super::compare_loose(entry.0, entry.1, None),
+                super::compare(entry.0, entry.1),
+            );
+        }
+    }
+
+    #[test]
+    fn compare_as_ref() {
+        use std::borrow::Cow;
+
+        let owned = String::from("1.2.3");
+        let cow: Cow<str> = Cow::Owned("1.2.4".into());
+
+        // Mix owned, borrowed and copy on write strings without converting them
+        assert_eq!(super::compare(&owned, "1.2.4"), Ok(Cmp::Lt));
+        assert_eq!(super::compare("1.2.4", owned.clone()), Ok(Cmp::Gt));
+        assert_eq!(super::compare(&owned, &cow), Ok(Cmp::Lt));
+        assert_eq!(super::compare(cow, owned), Ok(Cmp::Gt));
+        assert_eq!(
+            super::cmp(String::from("1.2"), "1.2.0"),
+            Ok(Ordering::Equal)
+        );
+        assert_eq!(
+            super::compare_to(String::from("1.2"), "1.3", Cmp::Lt),
+            Ok(true),
+        );
+    }
+
+    #[test]
+    fn cmp() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(super::cmp(entry.0, entry.1).ok(), entry.2.ord());
+        }
+
+        assert_eq!(super::cmp("1.2", "1.3"), Ok(Ordering::Less));
+        assert!(super::cmp("abc", "1.3").is_err());
+    }
+
+    #[test]
+    fn compare_to() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            // Test
+            assert!(super::compare_to(entry.0, entry.1, entry.2).unwrap());
+
+            // Make sure the inverse operator is not correct
+            assert!(!super::compare_to(entry.0, entry.1, entry.2.invert()).unwrap());
+        }
+
+        // Compare each error version in the version set
+        for entry in COMBIS_ERROR {
+            let result = super::compare_to(entry.0, entry.1, entry.2);
+
+            if let Ok(result) = result {
+                assert!(!result)
+            }
+        }
+
+        // Assert an exceptional case, compare to not equal
+        assert!(super::compare_to("1.2.3", "1.2", Cmp::Ne).unwrap());
+    }
+
+    #[test]
+    fn check() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(super::check(entry.0, entry.1, entry.2), Ok(Ok(())));
+            assert_eq!(
+                super::check(entry.0, entry.1, entry.2.invert()),
+                Ok(Err(entry.2)),
+            );
+        }
+
+        // Report the actual relation
+        assert_eq!(super::check("1.2", "1.3", Cmp::Ge), Ok(Err(Cmp::Lt)));
+        assert_eq!(super::check("1.2", "1.3", Cmp::Gt), Ok(Err(Cmp::Lt)));
+        assert_eq!(super::check("1.3", "1.2", Cmp::Le), Ok(Err(Cmp::Gt)));
+        assert_eq!(super::check("1.2", "1.2.0", Cmp::Lt), Ok(Err(Cmp::Eq)));
+        assert_eq!(super::check("1.2", "1.3", Cmp::Ne), Ok(Ok(())));
+
+        // Invalid versions
+        assert_eq!(super::check("1.2", "abc", Cmp::Lt), Err(()));
+    }
+
+    #[test]
+    fn compare_scheme() {
+        // The generic scheme matches compare
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(
+                super::compare_scheme(entry.0, entry.1, Scheme::Generic),
+                super::compare(entry.0, entry.1),
+            );
+        }
+
+        // Pairs that compare differently with PEP 440
+        for (a, b, generic, pep440) in [
+            ("1.0.post1", "1.0", Cmp::Lt, Cmp::Gt),
+            ("1.0c1", "1.0rc1", Cmp::Gt, Cmp::Eq),
+            ("1!1.0", "2.0", Cmp::Lt, Cmp::Gt),
+        ] {
+            assert_eq!(super::compare_scheme(a, b, Scheme::Generic), Ok(generic));
+            assert_eq!(super::compare_scheme(a, b, Scheme::Pep440), Ok(pep440));
+        }
+
+        assert!(super::compare_scheme("1.0 final", "1.0", Scheme::Pep440).is_err());
+    }
+
+    #[test]
+    fn php_compare() {
+        // Each operator of PHP's test cases
+        for (a, b, cmp) in [
+            ("1", "2", Cmp::Lt),
+            ("10", "2", Cmp::Gt),
+            ("1.0", "1.1", Cmp::Lt),
+            ("1.2", "1.0.1", Cmp::Gt),
+            ("1.0rc1", "1.0RC1", Cmp::Eq),
+        ] {
+            for operator in [Cmp::Eq, Cmp::Ne, Cmp::Lt, Cmp::Le, Cmp::Ge, Cmp::Gt] {
+                assert_eq!(
+                    super::php_compare(a, b, operator),
+                    satisfies(cmp, operator),
+                    "{} {} {}",
+                    a,
+                    operator.sign(),
+                    b,
+                );
+            }
+        }
+
+        // Differs from the generic comparison
+        assert!(super::php_compare("1.0", "1.0.0", Cmp::Lt));
+        assert!(super::php_compare("1.0-dev", "1.0-alpha", Cmp::Lt));
+        assert!(super::php_compare("", "1.0", Cmp::Lt));
+        assert!(super::php_compare("1.0.", "1.0.", Cmp::Lt));
+    }
+
+    #[test]
+    fn compare_opts() {
+        // Default options match compare
+        let options = CompareOptions::default();
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(
+                super::compare_opts(entry.0, entry.1, &options),
+                super::compare(entry.0, entry.1),
+            );
+        }
+
+        // Manifest options match comparing versions with that manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_some()) {
+            let options = CompareOptions::default().manifest(entry.3.unwrap());
+            assert_eq!(super::compare_opts(entry.0, entry.1, &options), Ok(entry.2));
+        }
+
+        // Combine case sensitivity with a maximum depth
+        let (a, b) = ("1.RC.2", "1.rc.1");
+        let manifest = Manifest::builder().max_depth(2);
+        for (manifest, result) in [
+            (Manifest::default(), Cmp::Gt),
+            (manifest.build(), Cmp::Eq),
+            (manifest.case_sensitive(true).build(), Cmp::Lt),
+        ] {
+            let options = CompareOptions::default().manifest(manifest);
+            assert_eq!(super::compare_opts(a, b, &options), Ok(result));
+        }
+
+        // Combine ignoring text with an overflow policy
+        let (a, b) = ("1.99999999999999999999-rc", "1.2147483647");
+        let manifest = Manifest::builder().ignore_text(true);
+        for (manifest, result) in [
+            (
+                manifest.overflow_policy(OverflowPolicy::Saturate).build(),
+                Ok(Cmp::Eq),
+            ),
+            (
+                manifest.overflow_policy(OverflowPolicy::Error).build(),
+                Err(()),
+            ),
+        ] {
+            let options = CompareOptions::default().manifest(manifest);
+            assert_eq!(super::compare_opts(a, b, &options), result);
+        }
+
+        // A scheme ignores the manifest
+        let options = CompareOptions::default()
+            .manifest(Manifest::builder().max_depth(1).build())
+            .scheme(Scheme::Pep440);
+        assert_eq!(
+            super::compare_opts("1.0.post1", "1.0", &options),
+            Ok(Cmp::Gt)
+        );
+        assert!(super::compare_opts("1.0 final", "1.0", &options).is_err());
+    }
+
+    #[test]
+    fn cmp_fn() {
+        let cmp = super::cmp_fn();
+
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(Some(cmp(entry.0, entry.1)), entry.2.ord());
+        }
+
+        // Invalid versions are ordered first
+        assert_eq!(cmp("abc", "1"), Ordering::Less);
+        assert_eq!(cmp("1", "abc"), Ordering::Greater);
+        assert_eq!(cmp("abc", "def"), Ordering::Less);
+
+        // Sort data by a version field
+        let mut packages = vec![
+            (String::from("c"), "2.0"),
+            (String::from("a"), "1.10.0"),
+            (String::from("b"), "1.9"),
+        ];
+        packages.sort_by(|a, b| cmp(a.1, b.1));
+        assert_eq!(
+            packages,
+            [
+                (String::from("b"), "1.9"),
+                (String::from("a"), "1.10.0"),
+                (String::from("c"), "2.0"),
+            ],
+        );
+    }
+
+    #[test]
+    fn cmp_display_fn() {
+        let cmp = super::cmp_display_fn();
+
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            if entry.2 != Cmp::Eq {
+                assert_eq!(Some(cmp(entry.0, entry.1)), entry.2.ord());
+            } else if entry.0 != entry.1 {
+                assert_ne!(cmp(entry.0, entry.1), Ordering::Equal);
+                assert_eq!(cmp(entry.0, entry.1), cmp(entry.1, entry.0).reverse());
+            }
+        }
+
+        // Equal spellings sort the same regardless of the original order
+        let expected = [
+            "abc", "1.1", "01.2", "1.02", "1.2", "1.2.0", "1.2.0.0", "1.3",
+        ];
+        let mut rng = Rng::new(1651);
+        for _ in 0..50 {
+            let mut versions = expected.to_vec();
+            for i in (1..versions.len()).rev() {
+                versions.swap(i, rng.below(i + 1));
+            }
+            versions.sort_by(|a, b| cmp(a, b));
+            assert_eq!(versions, expected);
+        }
+        assert_eq!(cmp("1.2", "1.2"), Ordering::Equal);
+    }
+
+    #[test]
+    fn merge_sorted() {
+        assert_eq!(
+            super::merge_sorted(&["1.0", "1.2", "2.0"], &["1.0.0", "1.5", "2", "3.0"]),
+            ["1.0", "1.2", "1.5", "2.0", "3.0"],
+        );
+
+        // Equal versions keep the first, from the first list
+        assert_eq!(
+            super::merge_sorted(&["1.0.0", "1.1"], &["1", "1.0", "1.1.0"]),
+            ["1.0.0", "1.1"],
+        );
+        assert_eq!(super::merge_sorted(&["1", "1.0"], &["1.0.0"]), ["1"],);
+
+        // Invalid versions are ordered first
+        assert_eq!(
+            super::merge_sorted(&["abc", "1.0"], &["0.9"]),
+            ["abc", "0.9", "1.0"],
+        );
+
+        assert!(super::merge_sorted(&[], &[]).is_empty());
+        assert_eq!(super::merge_sorted(&[], &["1.0"]), ["1.0"]);
+    }
+
+    #[test]
+    fn compare_prefix() {
+        for (a, b, cmp) in [
+            // The shorter version matches any trailing parts
+            ("1.2", "1.2.9", Cmp::Eq),
+            ("1.2.9", "1.2", Cmp::Eq),
+            ("1.2", "1.2.rc1", Cmp::Eq),
+            ("1", "1.2.3.4", Cmp::Eq),
+            ("1.2.3", "1.2.3", Cmp::Eq),
+            ("1.2", "1.2.0", Cmp::Eq),
+            // Diverging within the shared prefix
+            ("1.3", "1.2.9", Cmp::Gt),
+            ("1.2.9", "1.3", Cmp::Lt),
+            ("2", "1.9.9", Cmp::Gt),
+            ("1.2.3", "1.2.4.1", Cmp::Lt),
+            ("1.2.rc", "1.2.1", Cmp::Lt),
+            // A trailing build isn't part of the prefix, and only breaks ties
+            ("1.2 build 5", "1.2.9", Cmp::Eq),
+            ("1.2.9", "1.2 build 5", Cmp::Eq),
+            ("1.2 build 5", "1.2 build 6", Cmp::Lt),
+            ("1.2 build 5", "1.2", Cmp::Eq),
+            ("1.3 build 1", "1.2.9 build 2", Cmp::Gt),
+        ] {
+            assert_eq!(super::compare_prefix(a, b), Ok(cmp), "{} vs {}", a, b);
+        }
+
+        // A product name is only skipped if the manifest strips it
+        assert_eq!(super::compare_prefix("MyApp 1.2", "1.2.9"), Ok(Cmp::Lt));
+        let manifest = Manifest::builder().strip_product_name(true).build();
+        let a = Version::from_manifest("MyApp 1.2", &manifest).unwrap();
+        let b = Version::from_manifest("1.2.9", &manifest).unwrap();
+        assert_eq!(a.compare_prefix(&b), Cmp::Eq);
+
+        // Unlike compare, which pads with zeros
+        assert_eq!(super::compare("1.2", "1.2.9"), Ok(Cmp::Lt));
+        assert_eq!(super::compare_prefix("abc", "1.2"), Err(()));
+    }
+
+    #[test]
+    fn within() {
+        // Inside the tolerance window, in either direction
+        for (a, b, position, tolerance) in [
+            ("1.2.3", "1.2.5", 2, 3),
+            ("1.2.5", "1.2.3", 2, 3),
+            ("1.2.3", "1.2.6", 2, 3),
+            ("1.2.3", "1.2.3", 2, 0),
+            ("1.2", "1.2.2", 2, 2),
+            ("1.2.3", "1.4.0", 1, 2),
+            ("1.2.3-rc1", "1.2.4", 2, 1),
+            ("1.2.rc", "1.2.rc", 2, 0),
+            ("1.rc.3", "1.RC.4", 2, 1),
+            ("1.2.rc", "1.2.RC", 2, 0),
+            ("1.2", "1.2", 5, 0),
+            ("1.2.0.0", "1.2", 3, 0),
+        ] {
+            assert_eq!(
+                super::within(a, b, position, tolerance),
+                Ok(true),
+                "{} ~ {}",
+                a,
+                b
+            );
+        }
+
+        // Outside the tolerance window, or differing before the position
+        for (a, b, position, tolerance) in [
+            ("1.2.3", "1.2.7", 2, 3),
+            ("1.2.7", "1.2.3", 2, 3),
+            ("1.2.3", "1.3.3", 2, 3),
+            ("1.2.3", "2.2.3", 2, 3),
+            ("1.2.3", "1.2.4", 2, 0),
+            ("1.2.rc", "1.2.beta", 2, 5),
+            ("1.2.rc", "1.2.0", 2, 5),
+        ] {
+            assert_eq!(
+                super::within(a, b, position, tolerance),
+                Ok(false),
+                "{} ~ {}",
+                a,
+                b
+            );
+        }
+
+        assert_eq!(super::within("abc", "1.2", 0, 1), Err(()));
+    }
+
+    #[test]
+    fn group_by_minor() {
+        let lines = super::group_by_minor(&["1.2.0", "1.2.3", "1.3.0", "2.0.1"]);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[&(1, 2)], ["1.2.0", "1.2.3"]);
+        assert_eq!(lines[&(1, 3)], ["1.3.0"]);
+        assert_eq!(lines[&(2, 0)], ["2.0.1"]);
+
+        // Each line is sorted, missing or text minor numbers are zero
+        let lines = super::group_by_minor(&[
+            "1.2.10", "2-rc1", "1.2.9", "2", "1.2", "2.0.1", "abc", "rc.1", "1.2.0",
+        ]);
+        assert_eq!(
+            lines.into_iter().collect::<Vec<_>>(),
+            [
+                ((1, 2), vec!["1.2", "1.2.0", "1.2.9", "1.2.10"]),
+                ((2, 0), vec!["2-rc1", "2", "2.0.1"]),
+            ],
+        );
+
+        assert!(super::group_by_minor(&[]).is_empty());
+    }
+
+    #[test]
+    fn dedup() {
+        assert_eq!(
+            super::dedup(&["1.2", "1.2.0", "1.2.0.0", "1.3"]),
+            ["1.2", "1.3"],
+        );
+
+        // Order is preserved, versions need not be sorted or adjacent
+        assert_eq!(
+            super::dedup(&["2.0", "1.0", "2", "abc", "1.0.0", "abc", "def"]),
+            ["2.0", "1.0", "abc", "def"],
+        );
+
+        assert!(super::dedup(&[]).is_empty());
+
+        // Many duplicates spelled differently, keeping the first of each
+        let versions: Vec<String> = (0..200)
+            .map(|i| format!("{}{}", i % 10, ".0".repeat(i / 10)))
+            .collect();
+        let versions: Vec<&str> = versions.iter().map(String::as_str).collect();
+        assert_eq!(
+            super::dedup(&versions),
+            ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"],
+        );
+    }
+
+    #[test]
+    fn never_panic() {
+        let manifests = [
+            Manifest::default(),
+            Manifest::builder().max_depth(2).build(),
+            Manifest::builder().ignore_text(true).build(),
+            Manifest::builder().gnu_ordering(true).build(),
+            Manifest::builder().case_sensitive(true).build(),
+            Manifest::builder().split_alphanumeric(true).build(),
+            Manifest::builder().underscore_update(true).build(),
+            Manifest::builder().longer_is_greater(true).build(),
+            Manifest::builder().reject_empty(true).build(),
+            Manifest::builder().strip_product_name(true).build(),
+            Manifest::builder().compare_numbers_only(true).build(),
+            Manifest::builder()
+                .overflow_policy(OverflowPolicy::Saturate)
+                .build(),
+            Manifest::builder()
+                .overflow_policy(OverflowPolicy::Error)
+                .build(),
+            Manifest::builder().strict(true).build(),
+            Manifest::builder().min_depth(3).build(),
+            Manifest::builder()
+                .prerelease_keywords(crate::PRERELEASE_KEYWORDS)
+                .build(),
+            Manifest::builder().strict_separators(true).build(),
+            Manifest::builder().max_len(8).build(),
+            Manifest::builder()
+                .strip_prefixes(&["v", "rc"])
+                .strip_suffixes(&["-dev", "."])
+                .build(),
+            Manifest::builder().thousands_separators(true).build(),
+        ];
+
+        for seed in 1..=2000 {
+            let mut rng = Rng::new(seed);
+            let (a, b) = (rng.version(), rng.version());
+
+            // Parsing never panics, and comparing is consistent both ways
+            for manifest in &manifests {
+                let (lhs, rhs) = match (
+                    Version::from_manifest(&a, manifest),
+                    Version::from_manifest(&b, manifest),
+                ) {
+                    (Some(lhs), Some(rhs)) => (lhs, rhs),
+                    _ => continue,
+                };
+                assert_eq!(
+                    lhs.compare(&rhs).flip(),
+                    rhs.compare(&lhs),
+                    "Testing {:?} with {:?} and {:?} (seed {})",
+                    manifest,
+                    a,
+                    b,
+                    seed,
+                );
+                assert_eq!(lhs.compare(&lhs), Cmp::Eq);
+            }
+            for &scheme in Scheme::ALL {
+                let ab = scheme.compare(&a, &b);
+                let ba = scheme.compare(&b, &a);
+                assert_eq!(
+                    ab.map(Cmp::flip),
+                    ba,
+                    "Testing {:?} with {:?} and {:?} (seed {})",
+                    scheme,
+                    a,
+                    b,
+                    seed,
+                );
+                if ab.is_ok() {
+                    assert_eq!(
+                        scheme.compare(&a, &a),
+                        Ok(Cmp::Eq),
+                        "Testing {:?} with {:?} (seed {})",
+                        scheme,
+                        a,
+                        seed,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn properties() {
+        let manifests = [
+            Manifest::default(),
+            Manifest::builder().max_depth(2).build(),
+            Manifest::builder().ignore_text(true).build(),
+            Manifest::builder().gnu_ordering(true).build(),
+            Manifest::builder().case_sensitive(true).build(),
+            Manifest::builder().split_alphanumeric(true).build(),
+        ];
+
+        for seed in 1..=5000 {
+            let mut rng = Rng::new(seed);
+            let versions = [rng.version(), rng.version(), rng.version()];
+
+            for manifest in &manifests {
+                let parsed: Vec<Version> = versions
+                    .iter()
+                    .filter_map(|v| Version::from_manifest(v, manifest))
+                    .collect();
+                if parsed.len() < 3 {
+                    continue;
+                }
+                let (a, b, c) = (&parsed[0], &parsed[1], &parsed[2]);
+
+                // Reflexive and antisymmetric
+                assert_eq!(a.compare(a), Cmp::Eq, "{:?} (seed {})", versions, seed);
+                assert_eq!(
+                    a.compare(b),
+                    b.compare(a).flip(),
+                    "{:?} with {:?} (seed {})",
+                    versions,
+                    manifest,
+                    seed,
+                );
+
+                // Transitive
+                for (x, y, z) in [
+                    (a, b, c),
+                    (a, c, b),
+                    (b, a, c),
+                    (b, c, a),
+                    (c, a, b),
+                    (c, b, a),
+                ] {
+                    let (xy, yz) = (x.compare(y), y.compare(z));
+                    if xy == yz || yz == Cmp::Eq {
+                        assert_eq!(
+                            x.compare(z),
+                            xy,
+                            "Testing that {:?} {} {:?} {} {:?} with {:?} (seed {})",
+                            x.as_str(),
+                            xy.sign(),
+                            y.as_str(),
+                            yz.sign(),
+                            z.as_str(),
+                            manifest,
+                            seed,
+                        );
+                    }
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,441 @@
+//! Version constraint module, which provides the `Constraint` struct.
+//!
+//! A constraint is a set of comparison operators with versions, such as `>= 1.2, < 2.0`, that a
+//! version must all satisfy to match the constraint. Alternative sets may be separated by `||`,
+//! such as `^1.0 || ^2.0`, of which a version must satisfy any.
+
+use crate::{Cmp, Version};
+
+/// Version constraint struct, a set of comparison clauses a version must all satisfy.
+///
+/// A constraint may have multiple alternative sets of clauses, a version matches if it satisfies
+/// all clauses of any alternative.
+///
+/// A constraint in string format can be parsed using `Constraint::from(">= 1.2, < 2.0")`.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Constraint, Version};
+///
+/// let constraint = Constraint::from(">= 1.2, != 1.5, < 2.0").unwrap();
+///
+/// assert!(constraint.matches(&Version::from("1.4").unwrap()));
+/// assert!(!constraint.matches(&Version::from("1.5").unwrap()));
+/// assert!(!constraint.matches(&Version::from("2.0").unwrap()));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraint<'a> {
+    alternatives: Vec<Vec<(Cmp, Version<'a>)>>,
+}
+
+impl<'a> Constraint<'a> {
+    /// Create a `Constraint` instance from a constraint string.
+    ///
+    /// The constraint string consists of comma separated clauses, each being a comparison
+    /// operator sign followed by a version, such as `>= 1.2, < 2.0`. A clause without an operator
+    /// sign is an equality clause. An empty constraint string matches any version.
+    ///
+    /// A caret clause such as `^1.2.3` matches versions compatible with the given version, and
+    /// is equal to `>= 1.2.3, < 2`. The first non-zero release number is kept, so `^0.1.2` is
+    /// equal to `>= 0.1.2, < 0.2`.
+    ///
+    /// A clause may also be an inclusive hyphen range such as `1.2.3 - 1.5.0`, which is equal to
+    /// `>= 1.2.3, <= 1.5.0`. Like npm, a partial upper bound includes its whole release line, so
+    /// `1.2 - 2` is equal to `>= 1.2, < 3`.
+    ///
+    /// Alternative sets of clauses are separated by `||`, such as `^1.0.0 || ^2.0.0`.
+    ///
+    /// `None` is returned if any of the clauses is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Constraint};
+    ///
+    /// let constraint = Constraint::from(">= 1.2, < 2.0").unwrap();
+    ///
+    /// assert_eq!(constraint.clauses()[0].0, Cmp::Ge);
+    /// assert_eq!(constraint.clauses()[1].0, Cmp::Lt);
+    /// assert!(Constraint::from(">= 1.2, ~ 2.0").is_none());
+    ///
+    /// let range = Constraint::from("1.2.3 - 1.5.0").unwrap();
+    /// assert_eq!(range.clauses()[0].0, Cmp::Ge);
+    /// assert_eq!(range.clauses()[1].0, Cmp::Le);
+    ///
+    /// let either = Constraint::from("^1.0.0 || ^2.0.0").unwrap();
+    /// assert_eq!(either.alternatives().len(), 2);
+    /// ```
+    pub fn from(constraint: &'a str) -> Option<Self> {
+        // An empty constraint has no clauses
+        if constraint.trim().is_empty() {
+            return Some(Constraint {
+                alternatives: vec![vec![]],
+            });
+        }
+
+        Some(Constraint {
+            alternatives: constraint
+                .split("||")
+                .map(parse_clauses)
+                .collect::<Option<_>>()?,
+        })
+    }
+
+    /// Create a `Constraint` instance from a `requirements.txt` style line.
+    ///
+    /// The line consists of a package name followed by a constraint, such as
+    /// `package >= 1.2, != 1.5, < 2.0`. Extras in brackets, environment markers after a `;` and
+    /// comments after a `#` are ignored.
+    ///
+    /// The package name and constraint are returned, `None` is returned if the line is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Constraint, Version};
+    ///
+    /// let (name, constraint) = Constraint::from_requirement("requests >= 2.8.1, < 3").unwrap();
+    ///
+    /// assert_eq!(name, "requests");
+    /// assert!(constraint.matches(&Version::from("2.31.0").unwrap()));
+    /// ```
+    pub fn from_requirement(line: &'a str) -> Option<(&'a str, Self)> {
+        // Strip comments and environment markers
+        let line = line.split(|c| c == '#' || c == ';').next()?.trim();
+
+        // Take the package name
+        let at = line
+            .find(|c: char| !c.is_alphanumeric() && !matches!(c, '-' | '_' | '.'))
+            .unwrap_or(line.len());
+        let (name, mut rest) = line.split_at(at);
+        if name.is_empty() {
+            return None;
+        }
+
+        // Skip any extras
+        if rest.trim_start().starts_with('[') {
+            rest = &rest[rest.find(']')? + 1..];
+        }
+
+        Some((name, Constraint::from(rest)?))
+    }
+
+    /// Get the clauses of this constraint, each being an operator and a version.
+    ///
+    /// If this constraint has multiple alternatives, the clauses of the first alternative are
+    /// returned. Use `alternatives` to get all of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Constraint, Version};
+    ///
+    /// let constraint = Constraint::from("!= 1.5").unwrap();
+    ///
+    /// assert_eq!(constraint.clauses(), [(Cmp::Ne, Version::from("1.5").unwrap())]);
+    /// ```
+    pub fn clauses(&self) -> &[(Cmp, Version<'a>)] {
+        self.alternatives[0].as_slice()
+    }
+
+    /// Get the alternative sets of clauses of this constraint, separated by `||`.
+    ///
+    /// A constraint without `||` has a single alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Constraint, Version};
+    ///
+    /// let constraint = Constraint::from("< 1.0 || >= 2.0").unwrap();
+    ///
+    /// assert_eq!(constraint.alternatives(), [
+    ///     vec![(Cmp::Lt, Version::from("1.0").unwrap())],
+    ///     vec![(Cmp::Ge, Version::from("2.0").unwrap())],
+    /// ]);
+    /// ```
+    pub fn alternatives(&self) -> &[Vec<(Cmp, Version<'a>)>] {
+        self.alternatives.as_slice()
+    }
+
+    /// Check whether the given `version` satisfies all clauses of any alternative of this
+    /// constraint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Constraint, Version};
+    ///
+    /// let constraint = Constraint::from(">= 1.2, < 2.0").unwrap();
+    ///
+    /// assert!(constraint.matches(&Version::from("1.2").unwrap()));
+    /// assert!(!constraint.matches(&Version::from("2.0.0").unwrap()));
+    ///
+    /// let constraint = Constraint::from("^1.0.0 || ^2.0.0").unwrap();
+    ///
+    /// assert!(constraint.matches(&Version::from("2.3.0").unwrap()));
+    /// assert!(!constraint.matches(&Version::from("3.0.0").unwrap()));
+    /// ```
+    pub fn matches(&self, version: &Version) -> bool {
+        self.alternatives.iter().any(|clauses| {
+            clauses
+                .iter()
+                .all(|(operator, other)| version.compare_to(other, *operator))
+        })
+    }
+}
+
+/// Parse a set of comma separated constraint clauses.
+fn parse_clauses(clauses: &str) -> Option<Vec<(Cmp, Version<'_>)>> {
+    let mut parsed = vec![];
+    for clause in clauses.split(',') {
+        if let Some((low, high)) = clause.split_once(" - ") {
+            parsed.extend_from_slice(&parse_hyphen_range(low, high)?);
+        } else if let Some(version) = clause.trim().strip_prefix('^') {
+            parsed.extend_from_slice(&parse_caret(version)?);
+        } else {
+            parsed.push(parse_clause(clause)?);
+        }
+    }
+    Some(parsed)
+}
+
+/// Parse a single constraint clause, an operator sign followed by a version.
+fn parse_clause(clause: &str) -> Option<(Cmp, Version<'_>)> {
+    let clause = clause.trim();
+
+    // Split the operator sign from the version
+    let at = clause
+        .find(|c: char| c.is_alphanumeric() || c.is_whitespace())
+        .unwrap_or(clause.len());
+    let (sign, version) = clause.split_at(at);
+    let version = version.trim();
+    if version.is_empty() {
+        return None;
+    }
+
+    // Parse the operator, default to equality
+    let operator = if sign.is_empty() {
+        Cmp::Eq
+    } else {
+        Cmp::from_sign(sign).ok()?
+    };
+
+    Some((operator, Version::from(version)?))
+}
+
+/// Parse an inclusive hyphen range from its `low` and `high` bound into two clauses.
+///
+/// A partial upper bound having less than three numbers matches its whole release line, and is
+/// turned into an exclusive bound on the next release.
+fn parse_hyphen_range<'a>(low: &'a str, high: &'a str) -> Option<[(Cmp, Version<'a>); 2]> {
+    // Both bounds must be a plain version without an operator sign
+    let (low, high) = (low.trim(), high.trim());
+    let is_bound = |bound: &str| bound.starts_with(char::is_alphanumeric);
+    if !is_bound(low) || !is_bound(high) {
+        return None;
+    }
+    let (low, high) = (Version::from(low)?, Version::from(high)?);
+
+    let len = high.parts().len();
+    let partial = len < 3 && high.parts().iter().all(|p| p.as_number().is_some());
+    let high = if partial {
+        (Cmp::Lt, high.next_release(len.max(1) - 1))
+    } else {
+        (Cmp::Le, high)
+    };
+
+    Some([(Cmp::Ge, low), high])
+}
+
+/// Parse a caret clause version into two clauses, matching versions compatible with it.
+///
+/// The upper bound is the next release at the first non-zero release number.
+fn parse_caret(version: &str) -> Option<[(Cmp, Version<'_>); 2]> {
+    let version = version.trim();
+    if !version.starts_with(char::is_alphanumeric) {
+        return None;
+    }
+    let version = Version::from(version)?;
+
+    let release = version.strip_prerelease();
+    let len = release.parts().len().max(1);
+    let index = release
+        .parts()
+        .iter()
+        .position(|p| p.as_number() != Some(0))
+        .unwrap_or(len - 1);
+    let high = version.next_release(index);
+
+    Some([(Cmp::Ge, version), (Cmp::Lt, high)])
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::{Cmp, Version};
+
+    use super::Constraint;
+
+    #[test]
+    fn from() {
+        let constraint = Constraint::from(">= 1.2, != 1.5, < 2.0").unwrap();
+        assert_eq!(
+            constraint.clauses(),
+            [
+                (Cmp::Ge, Version::from("1.2").unwrap()),
+                (Cmp::Ne, Version::from("1.5").unwrap()),
+                (Cmp::Lt, Version::from("2.0").unwrap()),
+            ],
+        );
+
+        assert_eq!(
+            Constraint::from("1.2").unwrap().clauses(),
+            [(Cmp::Eq, Version::from("1.2").unwrap())],
+        );
+        assert!(Constraint::from("").unwrap().clauses().is_empty());
+
+        // Invalid constraints
+        assert!(Constraint::from(">=").is_none());
+        assert!(Constraint::from(">= 1.2,").is_none());
+        assert!(Constraint::from("=> 1.2").is_none());
+        assert!(Constraint::from(">= abc").is_none());
+    }
+
+    #[test]
+    fn from_hyphen_range() {
+        let constraint = Constraint::from("1.2.3 - 1.5.0").unwrap();
+        assert_eq!(
+            constraint.clauses(),
+            [
+                (Cmp::Ge, Version::from("1.2.3").unwrap()),
+                (Cmp::Le, Version::from("1.5.0").unwrap()),
+            ],
+        );
+        assert!(!constraint.matches(&Version::from("1.2.2").unwrap()));
+        assert!(constraint.matches(&Version::from("1.2.3").unwrap()));
+        assert!(constraint.matches(&Version::from("1.5.0").unwrap()));
+        assert!(!constraint.matches(&Version::from("1.5.1").unwrap()));
+
+        // Partial bounds
+        let constraint = Constraint::from("1.2 - 2").unwrap();
+        assert!(!constraint.matches(&Version::from("1.1.9").unwrap()));
+        assert!(constraint.matches(&Version::from("1.2.0").unwrap()));
+        assert!(constraint.matches(&Version::from("2.9.9").unwrap()));
+        assert!(!constraint.matches(&Version::from("3.0.0").unwrap()));
+
+        // Combined with other clauses
+        let constraint = Constraint::from("1.0 - 2.0.0, != 1.5").unwrap();
+        assert_eq!(constraint.clauses().len(), 3);
+        assert!(!constraint.matches(&Version::from("1.5.0").unwrap()));
+
+        // Invalid ranges
+        assert!(Constraint::from("1.2 - ").is_none());
+        assert!(Constraint::from(" - 1.2").is_none());
+        assert!(Constraint::from("1.2 - abc").is_none());
+        assert!(Constraint::from(">= 1.2 - 2").is_none());
+    }
+
+    #[test]
+    fn from_caret() {
+        for (constraint, high) in [
+            ("^1.2.3", "2"),
+            ("^1.0.0", "2"),
+            ("^0.1.2", "0.2"),
+            ("^0.0.3", "0.0.4"),
+            ("^1.2.3-rc1", "2"),
+            ("^0", "1"),
+        ] {
+            let version = constraint.strip_prefix('^').unwrap();
+            assert_eq!(
+                Constraint::from(constraint).unwrap().clauses(),
+                [
+                    (Cmp::Ge, Version::from(version).unwrap()),
+                    (Cmp::Lt, Version::from(high).unwrap()),
+                ],
+            );
+        }
+
+        assert!(Constraint::from("^").is_none());
+        assert!(Constraint::from("^>= 1.2").is_none());
+    }
+
+    #[test]
+    fn from_alternatives() {
+        let constraint = Constraint::from("^1.0.0 || ^2.0.0").unwrap();
+        assert_eq!(constraint.alternatives().len(), 2);
+        assert!(constraint.matches(&Version::from("1.4.0").unwrap()));
+        assert!(constraint.matches(&Version::from("2.3.0").unwrap()));
+        assert!(!constraint.matches(&Version::from("0.9.0").unwrap()));
+        assert!(!constraint.matches(&Version::from("3.0.0").unwrap()));
+
+        // Alternatives with multiple clauses
+        let constraint = Constraint::from(">= 1.0, < 1.5 || 2.0 - 2.5.0 || 3.1").unwrap();
+        assert_eq!(
+            constraint.alternatives(),
+            [
+                vec![
+                    (Cmp::Ge, Version::from("1.0").unwrap()),
+                    (Cmp::Lt, Version::from("1.5").unwrap()),
+                ],
+                vec![
+                    (Cmp::Ge, Version::from("2.0").unwrap()),
+                    (Cmp::Le, Version::from("2.5.0").unwrap()),
+                ],
+                vec![(Cmp::Eq, Version::from("3.1").unwrap())],
+            ],
+        );
+        assert!(constraint.matches(&Version::from("1.2").unwrap()));
+        assert!(!constraint.matches(&Version::from("1.7").unwrap()));
+        assert!(constraint.matches(&Version::from("2.5").unwrap()));
+        assert!(constraint.matches(&Version::from("3.1.0").unwrap()));
+        assert!(!constraint.matches(&Version::from("3.2").unwrap()));
+
+        // Invalid alternatives
+        assert!(Constraint::from("1.0 ||").is_none());
+        assert!(Constraint::from("|| 1.0").is_none());
+    }
+
+    #[test]
+    fn from_requirement() {
+        // Multiple clauses
+        let (name, constraint) =
+            Constraint::from_requirement("package >= 1.2, != 1.5, < 2.0").unwrap();
+        assert_eq!(name, "package");
+        assert_eq!(constraint.clauses().len(), 3);
+        assert!(constraint.matches(&Version::from("1.2").unwrap()));
+        assert!(constraint.matches(&Version::from("1.9.9").unwrap()));
+        assert!(!constraint.matches(&Version::from("1.1").unwrap()));
+        assert!(!constraint.matches(&Version::from("1.5.0").unwrap()));
+        assert!(!constraint.matches(&Version::from("2.0").unwrap()));
+
+        // Single equality clause
+        let (name, constraint) = Constraint::from_requirement("my_package==1.0.3").unwrap();
+        assert_eq!(name, "my_package");
+        assert_eq!(
+            constraint.clauses(),
+            [(Cmp::Eq, Version::from("1.0.3").unwrap())],
+        );
+
+        // Extras, markers and comments
+        let (name, constraint) = Constraint::from_requirement(
+            "requests[security] >= 2.8 ; python_version < '3' # comment",
+        )
+        .unwrap();
+        assert_eq!(name, "requests");
+        assert_eq!(
+            constraint.clauses(),
+            [(Cmp::Ge, Version::from("2.8").unwrap())],
+        );
+
+        // No constraint
+        let (name, constraint) = Constraint::from_requirement("package").unwrap();
+        assert_eq!(name, "package");
+        assert!(constraint.clauses().is_empty());
+
+        // Invalid lines
+        assert!(Constraint::from_requirement(">= 1.2").is_none());
+        assert!(Constraint::from_requirement("package ~= 1.2").is_none());
+    }
+}
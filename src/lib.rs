@@ -84,20 +84,51 @@
 //!
 //! If that isn't certain however, `version-compare` makes comparing a breeze.
 //!
+//! Enable the `semver` feature to compare a `Version` directly with a
+//! `semver::Version`.
+//!
+//! Enable the `serde` feature to serialize a `Version` as its version string,
+//! or its parsed parts with `serialize_parts`.
+//!
 //! _[View complete README](https://github.com/timvisee/version-compare/blob/master/README.md)_
 
+mod cached;
+mod channel;
 mod cmp;
 mod compare;
+mod constraint;
 mod manifest;
+mod memo;
+mod options;
 mod part;
+mod registry;
+mod scheme;
+#[cfg(feature = "semver")]
+mod semver;
+#[cfg(feature = "serde")]
+mod serde;
 mod version;
 
 #[cfg(test)]
 mod test;
 
 // Re-exports
+pub use crate::cached::CachedVersion;
+pub use crate::channel::Channel;
 pub use crate::cmp::Cmp;
-pub use crate::compare::{compare, compare_to};
-pub use crate::manifest::Manifest;
-pub use crate::part::Part;
+pub use crate::compare::{
+    check, closest, cmp, cmp_display_fn, cmp_fn, compare, compare_explain, compare_ignore_epoch,
+    compare_ignoring, compare_loose, compare_opt, compare_opts, compare_parsed, compare_prefix,
+    compare_scheme, compare_strict, compare_to, dedup, group_by_minor, is_ne, merge_sorted, newer,
+    php_compare, relation, try_sort, within,
+};
+pub use crate::constraint::Constraint;
+pub use crate::manifest::{Manifest, ManifestBuilder, OverflowPolicy, PRERELEASE_KEYWORDS};
+pub use crate::memo::MemoCompare;
+pub use crate::options::CompareOptions;
+pub use crate::part::{OwnedPart, Part, SpelledPart};
+pub use crate::registry::ParserRegistry;
+pub use crate::scheme::{detect_scheme, is_valid, validate, Scheme};
+#[cfg(feature = "serde")]
+pub use crate::serde::serialize_parts;
 pub use crate::version::Version;
@@ -40,6 +40,185 @@ pub struct Manifest {
     ///
     /// Issue: https://github.com/timvisee/version-compare/issues/27
     pub gnu_ordering: bool,
+
+    /// Whether to compare text parts case sensitively.
+    ///
+    /// Text parts are compared case insensitively by default, so `RC1` equals `rc1`. Enabling this
+    /// compares text by its raw value instead, which orders uppercase before lowercase text.
+    pub case_sensitive: bool,
+
+    /// Split parts mixing letters and digits into alternating number and text parts.
+    ///
+    /// By default only a leading number is split off, parsing `2p10` as `2`, `p10`. Enabling this
+    /// splits all runs like RPM does, parsing it as `2`, `p`, `10` so the trailing number is
+    /// compared numerically.
+    pub split_alphanumeric: bool,
+
+    /// Whether to reject empty version strings.
+    ///
+    /// By default a version string without any alphanumeric characters, such as `""` or `" . "`,
+    /// is valid and has no parts, comparing equal to `0`. Enabling this makes parsing such a
+    /// version string fail instead.
+    pub reject_empty: bool,
+
+    /// Whether to strip a leading product name from version strings.
+    ///
+    /// By default leading text is a regular text part, so `MyApp 3.2.0` is less than `3.1`.
+    /// Enabling this skips all parts before the first part starting with a digit, so
+    /// `MyApp 3.2.0` equals `3.2.0`. A version string with nothing but a product name is invalid.
+    pub strip_product_name: bool,
+
+    /// Whether to compare just the number parts of versions.
+    ///
+    /// Unlike `ignore_text`, text parts are still parsed and available through `Version::parts`,
+    /// but they are skipped when comparing. So `1.2.3-rc1` equals `1.2.3`.
+    pub compare_numbers_only: bool,
+
+    /// How to handle number parts overflowing the number type.
+    ///
+    /// See `OverflowPolicy` for the available policies. Overflowing numbers are kept as text by
+    /// default.
+    pub overflow_policy: OverflowPolicy,
+
+    /// Whether to strictly parse version strings.
+    ///
+    /// By default parsing is permissive. Enabling this rejects version strings having text parts,
+    /// numbers overflowing the number type regardless of `overflow_policy`, or more parts than
+    /// `max_depth` rather than ignoring the excess parts.
+    pub strict: bool,
+
+    /// Whether to treat a trailing underscore separated number as update number.
+    ///
+    /// By default an underscore is a regular separator, so Java style `1.2.3_2` equals `1.2.3.2`.
+    /// Enabling this compares the trailing `_2` as update after all other parts, like a build
+    /// number. So `1.2.3_2` is greater than `1.2.3`, but less than `1.2.3.1` and `1.2.4`.
+    pub underscore_update: bool,
+
+    /// The minimum depth of a version number.
+    ///
+    /// This specifies the minimum number of parts, parsing a version string with fewer parts
+    /// fails. For example, a minimum of `3` requires a `major.minor.patch` version. There is no
+    /// minimum if `None` is set.
+    pub min_depth: Option<usize>,
+
+    /// The pre-release keywords with their ordering weights, to order text parts with.
+    ///
+    /// Text parts being a keyword, ignoring case and trailing digits, are ordered by weight
+    /// before any other text. Keywords of equal weight are equal, and are ordered by their
+    /// trailing number only. Other text is ordered alphabetically. So `1.0-alpha` is less than
+    /// `1.0-beta`, which is less than `1.0-rc1`, which equals `1.0-pre1`. If `None` is set, the
+    /// `PRERELEASE_KEYWORDS` table is used. Set an empty table to order all text alphabetically.
+    pub prerelease_keywords: Option<&'static [(&'static str, i32)]>,
+
+    /// Whether to reject misplaced separators in version strings.
+    ///
+    /// Any non-alphanumeric character separates parts. By default empty parts are skipped, so
+    /// `1..2` equals `1.2`, and `.1` and `1.` equal `1`. Enabling this makes parsing a non-empty
+    /// version string fail if it has a leading, trailing or repeated separator, including
+    /// surrounding whitespace.
+    pub strict_separators: bool,
+
+    /// Whether a longer version is greater than a version it has as prefix.
+    ///
+    /// By default missing parts are zero, so `1.2.0` equals `1.2`. Enabling this makes each extra
+    /// number part significant, even if zero, so `1.2.0` is greater than `1.2`. An extra text part
+    /// is still less than nothing, so `1.2-rc` remains less than `1.2`.
+    pub longer_is_greater: bool,
+
+    /// The maximum length of a version string in bytes.
+    ///
+    /// Parsing a longer version string fails before splitting it into parts. This is a cheap
+    /// guard against pathological untrusted input. There is no limit if `None` is set.
+    pub max_len: Option<usize>,
+
+    /// Prefixes to strip from version strings before parsing.
+    ///
+    /// The first listed prefix the version string starts with is stripped, to remove project
+    /// specific decorations. So with `release-` listed, `release-1.2.3` equals `1.2.3`. Prefixes
+    /// are matched case sensitively, and nothing is stripped by default.
+    pub strip_prefixes: &'static [&'static str],
+
+    /// Suffixes to strip from version strings before parsing.
+    ///
+    /// The first listed suffix the version string ends with is stripped, after stripping a
+    /// prefix. So with `-final` listed, `1.2.3-final` equals `1.2.3`. Suffixes are matched case
+    /// sensitively, and nothing is stripped by default.
+    pub strip_suffixes: &'static [&'static str],
+
+    /// Whether to allow commas as thousands separators in numbers.
+    ///
+    /// By default a comma is a regular separator, so `build 1,024` has the parts `build`, `1` and
+    /// `24`. Enabling this keeps a comma between a digit and a group of exactly three digits in
+    /// the number, so it has the parts `build` and `1024` instead. Other commas, such as in
+    /// `1,2,3`, still separate parts. A number overflowing the number type that is kept as text
+    /// following `overflow_policy` is split at its commas instead.
+    pub thousands_separators: bool,
+}
+
+/// The well-known pre-release keywords with their ordering weights.
+///
+/// This orders `dev` before `alpha`, `beta`, `rc` and `snapshot`, the abbreviations `a` and `b`
+/// are equal to `alpha` and `beta`, and `pre` is equal to `rc`. Used to order text parts unless
+/// overridden with `Manifest::prerelease_keywords`.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{compare, Cmp, Manifest, Version, PRERELEASE_KEYWORDS};
+///
+/// assert_eq!(compare("1.0-dev", "1.0-alpha"), Ok(Cmp::Lt));
+/// assert_eq!(compare("1.0-a1", "1.0-alpha1"), Ok(Cmp::Eq));
+/// assert_eq!(compare("1.0-pre", "1.0-rc"), Ok(Cmp::Eq));
+///
+/// // Order snapshots first
+/// let keywords = &[("snapshot", 0), ("alpha", 1)];
+/// let manifest = Manifest::builder().prerelease_keywords(keywords).build();
+/// let a = Version::from_manifest("1.0-snapshot", &manifest).unwrap();
+/// let b = Version::from_manifest("1.0-alpha", &manifest).unwrap();
+/// assert!(a < b);
+/// ```
+pub const PRERELEASE_KEYWORDS: &[(&str, i32)] = &[
+    ("dev", 0),
+    ("alpha", 1),
+    ("a", 1),
+    ("beta", 2),
+    ("b", 2),
+    ("rc", 3),
+    ("pre", 3),
+    ("snapshot", 4),
+];
+
+/// Policy for number parts overflowing the number type, such as a 30 digit number.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, Manifest, OverflowPolicy, Version};
+///
+/// let manifest = Manifest::builder().overflow_policy(OverflowPolicy::Saturate).build();
+/// let a = Version::from_manifest("1.99999999999999999999", &manifest).unwrap();
+/// let b = Version::from_manifest("1.2147483647", &manifest).unwrap();
+///
+/// assert_eq!(a.compare(b), Cmp::Eq);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Fail parsing the version string.
+    Error,
+
+    /// Pin the number to the maximum number value.
+    Saturate,
+
+    /// Keep the number as text part, which is still compared by its numeric magnitude.
+    ///
+    /// This is the default.
+    AsText,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        OverflowPolicy::AsText
+    }
 }
 
 /// Version manifest implementation.
@@ -61,12 +240,177 @@ impl Manifest {
     pub fn has_max_depth(&self) -> bool {
         self.max_depth.is_some() && self.max_depth.unwrap() > 0
     }
+
+    /// Create a builder to construct a manifest with a fluent API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Manifest;
+    ///
+    /// let manifest = Manifest::builder().max_depth(3).ignore_text(true).build();
+    ///
+    /// assert_eq!(manifest.max_depth, Some(3));
+    /// assert!(manifest.ignore_text);
+    /// ```
+    pub fn builder() -> ManifestBuilder {
+        ManifestBuilder::default()
+    }
+}
+
+/// Version manifest builder.
+///
+/// Created with `Manifest::builder()`, starting with the default manifest.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ManifestBuilder {
+    manifest: Manifest,
+}
+
+impl ManifestBuilder {
+    /// Set the maximum depth of a version number, see `Manifest::max_depth`.
+    #[must_use]
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.manifest.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Set whether to ignore text parts, see `Manifest::ignore_text`.
+    #[must_use]
+    pub fn ignore_text(mut self, ignore_text: bool) -> Self {
+        self.manifest.ignore_text = ignore_text;
+        self
+    }
+
+    /// Set whether to use GNU sort based ordering, see `Manifest::gnu_ordering`.
+    #[must_use]
+    pub fn gnu_ordering(mut self, gnu_ordering: bool) -> Self {
+        self.manifest.gnu_ordering = gnu_ordering;
+        self
+    }
+
+    /// Set whether to compare text case sensitively, see `Manifest::case_sensitive`.
+    #[must_use]
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.manifest.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set whether to split mixed alphanumeric parts, see `Manifest::split_alphanumeric`.
+    #[must_use]
+    pub fn split_alphanumeric(mut self, split_alphanumeric: bool) -> Self {
+        self.manifest.split_alphanumeric = split_alphanumeric;
+        self
+    }
+
+    /// Set whether to reject empty version strings, see `Manifest::reject_empty`.
+    #[must_use]
+    pub fn reject_empty(mut self, reject_empty: bool) -> Self {
+        self.manifest.reject_empty = reject_empty;
+        self
+    }
+
+    /// Set whether to strip a leading product name, see `Manifest::strip_product_name`.
+    #[must_use]
+    pub fn strip_product_name(mut self, strip_product_name: bool) -> Self {
+        self.manifest.strip_product_name = strip_product_name;
+        self
+    }
+
+    /// Set whether to compare just number parts, see `Manifest::compare_numbers_only`.
+    #[must_use]
+    pub fn compare_numbers_only(mut self, compare_numbers_only: bool) -> Self {
+        self.manifest.compare_numbers_only = compare_numbers_only;
+        self
+    }
+
+    /// Set how to handle overflowing number parts, see `Manifest::overflow_policy`.
+    #[must_use]
+    pub fn overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.manifest.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// Set whether to strictly parse version strings, see `Manifest::strict`.
+    #[must_use]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.manifest.strict = strict;
+        self
+    }
+
+    /// Set whether to treat a trailing `_N` as update number, see `Manifest::underscore_update`.
+    #[must_use]
+    pub fn underscore_update(mut self, underscore_update: bool) -> Self {
+        self.manifest.underscore_update = underscore_update;
+        self
+    }
+
+    /// Set the minimum depth of a version number, see `Manifest::min_depth`.
+    #[must_use]
+    pub fn min_depth(mut self, min_depth: usize) -> Self {
+        self.manifest.min_depth = Some(min_depth);
+        self
+    }
+
+    /// Set the pre-release keywords to order text parts with, see
+    /// `Manifest::prerelease_keywords`.
+    #[must_use]
+    pub fn prerelease_keywords(mut self, keywords: &'static [(&'static str, i32)]) -> Self {
+        self.manifest.prerelease_keywords = Some(keywords);
+        self
+    }
+
+    /// Set whether to reject misplaced separators, see `Manifest::strict_separators`.
+    #[must_use]
+    pub fn strict_separators(mut self, strict_separators: bool) -> Self {
+        self.manifest.strict_separators = strict_separators;
+        self
+    }
+
+    /// Set whether a longer version is greater, see `Manifest::longer_is_greater`.
+    #[must_use]
+    pub fn longer_is_greater(mut self, longer_is_greater: bool) -> Self {
+        self.manifest.longer_is_greater = longer_is_greater;
+        self
+    }
+
+    /// Set the maximum length of a version string, see `Manifest::max_len`.
+    #[must_use]
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.manifest.max_len = Some(max_len);
+        self
+    }
+
+    /// Set the prefixes to strip from version strings, see `Manifest::strip_prefixes`.
+    #[must_use]
+    pub fn strip_prefixes(mut self, prefixes: &'static [&'static str]) -> Self {
+        self.manifest.strip_prefixes = prefixes;
+        self
+    }
+
+    /// Set the suffixes to strip from version strings, see `Manifest::strip_suffixes`.
+    #[must_use]
+    pub fn strip_suffixes(mut self, suffixes: &'static [&'static str]) -> Self {
+        self.manifest.strip_suffixes = suffixes;
+        self
+    }
+
+    /// Set whether to allow thousands separators, see `Manifest::thousands_separators`.
+    #[must_use]
+    pub fn thousands_separators(mut self, thousands_separators: bool) -> Self {
+        self.manifest.thousands_separators = thousands_separators;
+        self
+    }
+
+    /// Build the manifest.
+    pub fn build(self) -> Manifest {
+        self.manifest
+    }
 }
 
 #[cfg_attr(tarpaulin, skip)]
 #[cfg(test)]
 mod tests {
-    use super::Manifest;
+    use super::{Manifest, OverflowPolicy};
 
     #[test]
     #[allow(clippy::field_reassign_with_default)]
@@ -82,4 +426,57 @@ mod tests {
         manifest.max_depth = None;
         assert!(!manifest.has_max_depth());
     }
+
+    #[test]
+    fn builder() {
+        assert_eq!(Manifest::builder().build(), Manifest::default());
+
+        let manifest = Manifest::builder()
+            .max_depth(3)
+            .ignore_text(true)
+            .gnu_ordering(true)
+            .case_sensitive(true)
+            .split_alphanumeric(true)
+            .reject_empty(true)
+            .strip_product_name(true)
+            .compare_numbers_only(true)
+            .overflow_policy(OverflowPolicy::Error)
+            .strict(true)
+            .underscore_update(true)
+            .min_depth(2)
+            .prerelease_keywords(&[("alpha", 1)])
+            .strict_separators(true)
+            .longer_is_greater(true)
+            .max_len(64)
+            .strip_prefixes(&["release-"])
+            .strip_suffixes(&["-final"])
+            .thousands_separators(true)
+            .build();
+        assert_eq!(manifest.max_depth, Some(3));
+        assert!(manifest.ignore_text);
+        assert!(manifest.gnu_ordering);
+        assert!(manifest.case_sensitive);
+        assert!(manifest.split_alphanumeric);
+        assert!(manifest.reject_empty);
+        assert!(manifest.strip_product_name);
+        assert!(manifest.compare_numbers_only);
+        assert_eq!(manifest.overflow_policy, OverflowPolicy::Error);
+        assert!(manifest.strict);
+        assert!(manifest.underscore_update);
+        assert_eq!(manifest.min_depth, Some(2));
+        assert_eq!(manifest.prerelease_keywords, Some(&[("alpha", 1)][..]));
+        assert!(manifest.strict_separators);
+        assert!(manifest.longer_is_greater);
+        assert_eq!(manifest.max_len, Some(64));
+        assert_eq!(manifest.strip_prefixes, ["release-"]);
+        assert_eq!(manifest.strip_suffixes, ["-final"]);
+        assert!(manifest.thousands_separators);
+
+        // Later calls override earlier ones
+        let manifest = Manifest::builder()
+            .ignore_text(true)
+            .ignore_text(false)
+            .build();
+        assert!(!manifest.ignore_text);
+    }
 }
@@ -0,0 +1,155 @@
+//! Memoized compare module.
+//!
+//! A module that provides the `MemoCompare` struct, caching comparison results of version
+//! string pairs.
+
+use std::collections::HashMap;
+
+use crate::Cmp;
+
+/// Memoizing comparator, caching the results of `compare` by version string pair.
+///
+/// This is useful for batch pipelines comparing the same pairs of version strings repeatedly.
+/// Each pair is parsed and compared once, later comparisons of the same pair use the cached
+/// result. Results are cached in order, comparing `b` to `a` doesn't use the result of comparing
+/// `a` to `b`. Invalid version strings are not cached.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Cmp, MemoCompare};
+///
+/// let mut memo = MemoCompare::new();
+///
+/// assert_eq!(memo.compare("1.2.3", "1.2.4"), Ok(Cmp::Lt));
+/// assert_eq!(memo.compare("1.2.3", "1.2.4"), Ok(Cmp::Lt));
+/// assert_eq!(memo.hits(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoCompare {
+    cache: HashMap<(String, String), Cmp>,
+    hits: usize,
+}
+
+impl MemoCompare {
+    /// Create a memoizing comparator with an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compare two version number strings to each other, as in `compare`.
+    ///
+    /// The result is taken from the cache if this pair was compared before.
+    ///
+    /// If either version number string is invalid an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, MemoCompare};
+    ///
+    /// let mut memo = MemoCompare::new();
+    ///
+    /// assert_eq!(memo.compare("1.2", "1.2.0"), Ok(Cmp::Eq));
+    /// assert_eq!(memo.compare("abc", "1.2"), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn compare<A, B>(&mut self, a: A, b: B) -> Result<Cmp, ()>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        let key = (a.as_ref().to_string(), b.as_ref().to_string());
+        if let Some(cmp) = self.cache.get(&key) {
+            self.hits += 1;
+            return Ok(*cmp);
+        }
+
+        let cmp = crate::compare(&key.0, &key.1)?;
+        self.cache.insert(key, cmp);
+        Ok(cmp)
+    }
+
+    /// Get the number of comparisons that used a cached result.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Get the number of cached results.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Check whether no results are cached.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Clear all cached results, and reset the number of hits.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::test::{COMBIS, COMBIS_ERROR};
+    use crate::Cmp;
+
+    use super::MemoCompare;
+
+    #[test]
+    fn compare() {
+        let mut memo = MemoCompare::new();
+        let combis: Vec<_> = COMBIS.iter().filter(|c| c.3.is_none()).collect();
+
+        // Cached results must equal fresh results, both on a miss and a hit
+        for _ in 0..2 {
+            for entry in &combis {
+                assert_eq!(memo.compare(entry.0, entry.1), Ok(entry.2));
+                assert_eq!(
+                    memo.compare(entry.0, entry.1),
+                    crate::compare(entry.0, entry.1)
+                );
+            }
+        }
+        assert_eq!(memo.hits(), combis.len() * 3);
+        assert!(memo.len() <= combis.len());
+
+        // Results must equal fresh results for the error version set
+        for entry in COMBIS_ERROR {
+            assert_eq!(
+                memo.compare(entry.0, entry.1),
+                crate::compare(entry.0, entry.1)
+            );
+        }
+    }
+
+    #[test]
+    fn hits() {
+        let mut memo = MemoCompare::new();
+        assert!(memo.is_empty());
+
+        assert_eq!(memo.compare("1.2", "1.3"), Ok(Cmp::Lt));
+        assert_eq!(memo.hits(), 0);
+        assert_eq!(memo.compare("1.2", "1.3"), Ok(Cmp::Lt));
+        assert_eq!(memo.hits(), 1);
+
+        // Pairs are cached in order
+        assert_eq!(memo.compare("1.3", "1.2"), Ok(Cmp::Gt));
+        assert_eq!(memo.hits(), 1);
+        assert_eq!(memo.len(), 2);
+
+        // Errors are not cached
+        assert_eq!(memo.compare("abc", "1.2"), Err(()));
+        assert_eq!(memo.compare("abc", "1.2"), Err(()));
+        assert_eq!(memo.hits(), 1);
+        assert_eq!(memo.len(), 2);
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.hits(), 0);
+    }
+}
@@ -0,0 +1,82 @@
+//! Compare options module.
+//!
+//! A module that provides the `CompareOptions` struct, bundling all options for comparing version
+//! strings with `compare_opts`.
+
+use crate::{Manifest, Scheme};
+
+/// Options for comparing version strings, used with `compare_opts`.
+///
+/// Bundles the version manifest, configuring things like case sensitivity, ignoring text, the
+/// maximum depth and the overflow policy, with the version scheme to compare with.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{compare_opts, Cmp, CompareOptions, Manifest};
+///
+/// let options = CompareOptions::default()
+///     .manifest(Manifest::builder().max_depth(2).case_sensitive(true).build());
+///
+/// assert_eq!(compare_opts("1.RC.1", "1.rc.2", &options), Ok(Cmp::Lt));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CompareOptions {
+    /// The manifest to parse and compare versions with.
+    ///
+    /// The manifest is not used when comparing with a specific version scheme.
+    pub manifest: Manifest,
+
+    /// The version scheme to compare with.
+    ///
+    /// Defaults to `Scheme::Generic`, comparing versions following the manifest.
+    pub scheme: Scheme,
+}
+
+impl CompareOptions {
+    /// Set the manifest to parse and compare versions with, see `CompareOptions::manifest`.
+    #[must_use]
+    pub fn manifest(mut self, manifest: Manifest) -> Self {
+        self.manifest = manifest;
+        self
+    }
+
+    /// Set the version scheme to compare with, see `CompareOptions::scheme`.
+    #[must_use]
+    pub fn scheme(mut self, scheme: Scheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        CompareOptions {
+            manifest: Manifest::default(),
+            scheme: Scheme::Generic,
+        }
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::{Manifest, Scheme};
+
+    use super::CompareOptions;
+
+    #[test]
+    fn builder() {
+        let options = CompareOptions::default();
+        assert_eq!(options.manifest, Manifest::default());
+        assert_eq!(options.scheme, Scheme::Generic);
+
+        let manifest = Manifest::builder().ignore_text(true).build();
+        let options = CompareOptions::default()
+            .manifest(manifest)
+            .scheme(Scheme::Pep440);
+        assert_eq!(options.manifest, manifest);
+        assert_eq!(options.scheme, Scheme::Pep440);
+    }
+}
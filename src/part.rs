@@ -4,8 +4,11 @@
 //! parts. Each version string is broken down into these version parts when being parsed to a
 //! `Version`.
 
+use std::cmp::Ordering;
 use std::fmt;
 
+use crate::version::{compare_digits, compare_text, is_digits, is_overflowing_number};
+
 /// Version string part enum.
 ///
 /// Each version string is broken down into these version parts when being parsed to a `Version`.
@@ -22,6 +25,115 @@ pub enum Part<'a> {
     Text(&'a str),
 }
 
+impl<'a> Part<'a> {
+    /// Get the numeric value of this part, if it is a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Part;
+    ///
+    /// assert_eq!(Part::Number(3).as_number(), Some(3));
+    /// assert_eq!(Part::Text("rc").as_number(), None);
+    /// ```
+    pub fn as_number(&self) -> Option<i32> {
+        match self {
+            Part::Number(n) => Some(*n),
+            Part::Text(_) => None,
+        }
+    }
+
+    /// Get the text value of this part, if it is text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Part;
+    ///
+    /// assert_eq!(Part::Text("rc").as_text(), Some("rc"));
+    /// assert_eq!(Part::Number(3).as_text(), None);
+    /// ```
+    pub fn as_text(&self) -> Option<&'a str> {
+        match self {
+            Part::Number(_) => None,
+            Part::Text(t) => Some(t),
+        }
+    }
+
+    /// Check whether this part is empty, being a zero number or empty text.
+    ///
+    /// Empty parts don't affect comparison when trailing, `1.0` equals `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Part;
+    ///
+    /// assert!(Part::Number(0).is_empty());
+    /// assert!(!Part::Number(1).is_empty());
+    /// assert!(!Part::Text("rc").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Part::Number(n) => *n == 0,
+            Part::Text(t) => t.is_empty(),
+        }
+    }
+}
+
+/// Totally order version parts, as they compare in versions having the default manifest.
+///
+/// From low to high, parts are ordered as:
+///
+/// * Text, by pre-release keyword weight and case-insensitive value, see `PRERELEASE_KEYWORDS`
+/// * Numeric text fitting a number, such as `Part::Text("5")`, by numeric value
+/// * Numbers, by numeric value
+/// * Numeric text overflowing a number, such as `Part::Text("99999999999")`, by numeric value
+///
+/// Parts that are equal when comparing versions, such as `rc` and `RC`, are ordered by their raw
+/// value to be consistent with `Eq`.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::Part;
+///
+/// let mut parts = vec![Part::Number(2), Part::Text("rc"), Part::Number(1), Part::Text("alpha")];
+/// parts.sort();
+///
+/// assert_eq!(parts, [Part::Text("alpha"), Part::Text("rc"), Part::Number(1), Part::Number(2)]);
+/// ```
+impl<'a> Ord for Part<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Rank the type of each part first
+        let rank = |part: &Part| match part {
+            Part::Text(t) if is_overflowing_number(t) => 3,
+            Part::Number(_) => 2,
+            Part::Text(t) if is_digits(t) => 1,
+            Part::Text(_) => 0,
+        };
+
+        rank(self)
+            .cmp(&rank(other))
+            .then_with(|| match (self, other) {
+                (Part::Number(lhs), Part::Number(rhs)) => lhs.cmp(rhs),
+                (Part::Text(lhs), Part::Text(rhs)) if is_digits(lhs) => {
+                    compare_digits(lhs, rhs).then_with(|| lhs.cmp(rhs))
+                }
+                (Part::Text(lhs), Part::Text(rhs)) => {
+                    compare_text(lhs, rhs, None).then_with(|| lhs.cmp(rhs))
+                }
+                _ => unreachable!("parts of equal rank have the same type"),
+            })
+    }
+}
+
+impl<'a> PartialOrd for Part<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl<'a> fmt::Display for Part<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -31,14 +143,221 @@ impl<'a> fmt::Display for Part<'a> {
     }
 }
 
+/// Owned version part enum.
+///
+/// Like `Part`, but owning its text, so it isn't bound to the lifetime of the version string.
+/// Created using `Version::to_parts_owned` or `OwnedPart::from(part)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OwnedPart {
+    /// Numeric part, holds the numerical value.
+    Number(i32),
+
+    /// A text part, holds the owned string.
+    Text(String),
+}
+
+impl OwnedPart {
+    /// Borrow this owned part as `Part`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{OwnedPart, Part};
+    ///
+    /// let part = OwnedPart::Text("rc".into());
+    ///
+    /// assert_eq!(part.as_part(), Part::Text("rc"));
+    /// ```
+    pub fn as_part(&self) -> Part<'_> {
+        match self {
+            OwnedPart::Number(n) => Part::Number(*n),
+            OwnedPart::Text(t) => Part::Text(t),
+        }
+    }
+}
+
+impl<'a> From<Part<'a>> for OwnedPart {
+    fn from(part: Part<'a>) -> Self {
+        match part {
+            Part::Number(n) => OwnedPart::Number(n),
+            Part::Text(t) => OwnedPart::Text(t.into()),
+        }
+    }
+}
+
+/// Totally order owned version parts, the same as `Part`.
+impl Ord for OwnedPart {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_part().cmp(&other.as_part())
+    }
+}
+
+impl PartialOrd for OwnedPart {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for OwnedPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_part().fmt(f)
+    }
+}
+
+/// Version part with its original spelling.
+///
+/// Created using `Version::spelled_parts`, to reconstruct or normalize a version string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpelledPart<'a> {
+    /// The parsed part.
+    pub part: Part<'a>,
+
+    /// The original text preceding this part since the previous part, such as a `.` separator.
+    pub separator: &'a str,
+
+    /// The original text of this part, including leading zeros and original case.
+    pub text: &'a str,
+}
+
 #[cfg_attr(tarpaulin, skip)]
 #[cfg(test)]
 mod tests {
-    use super::Part;
+    use std::cmp::Ordering;
+
+    use crate::{Cmp, Version};
+
+    use super::{OwnedPart, Part};
+
+    #[test]
+    fn as_number() {
+        assert_eq!(Part::Number(0).as_number(), Some(0));
+        assert_eq!(Part::Number(123).as_number(), Some(123));
+        assert_eq!(Part::Text("123").as_number(), None);
+        assert_eq!(Part::Text("rc").as_number(), None);
+    }
+
+    #[test]
+    fn as_text() {
+        assert_eq!(Part::Text("rc").as_text(), Some("rc"));
+        assert_eq!(Part::Text("").as_text(), Some(""));
+        assert_eq!(Part::Number(123).as_text(), None);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(Part::Number(0).is_empty());
+        assert!(Part::Text("").is_empty());
+        assert!(!Part::Number(1).is_empty());
+        assert!(!Part::Text("0").is_empty());
+        assert!(!Part::Text("rc").is_empty());
+    }
+
+    #[test]
+    fn ord() {
+        // Sort mixed parts into the documented cross-type order
+        let mut parts = vec![
+            Part::Number(10),
+            Part::Text("99999999999"),
+            Part::Text("beta"),
+            Part::Number(0),
+            Part::Text("5"),
+            Part::Text("zeta"),
+            Part::Text("RC"),
+            Part::Number(2),
+            Part::Text("dev"),
+            Part::Text("2147483648"),
+            Part::Text("rc"),
+            Part::Text(""),
+        ];
+        parts.sort();
+        assert_eq!(
+            parts,
+            [
+                Part::Text("dev"),
+                Part::Text("beta"),
+                Part::Text("RC"),
+                Part::Text("rc"),
+                Part::Text(""),
+                Part::Text("zeta"),
+                Part::Text("5"),
+                Part::Number(0),
+                Part::Number(2),
+                Part::Number(10),
+                Part::Text("2147483648"),
+                Part::Text("99999999999"),
+            ],
+        );
+
+        // Owned parts sort the same
+        let mut owned: Vec<OwnedPart> = parts.iter().rev().copied().map(OwnedPart::from).collect();
+        owned.sort();
+        assert!(owned
+            .iter()
+            .map(OwnedPart::as_part)
+            .eq(parts.iter().copied()));
+    }
+
+    #[test]
+    fn ord_matches_compare() {
+        let parts = [
+            Part::Number(0),
+            Part::Number(1),
+            Part::Number(i32::MAX),
+            Part::Text(""),
+            Part::Text("0"),
+            Part::Text("7"),
+            Part::Text("0099999999999"),
+            Part::Text("2147483648"),
+            Part::Text("alpha"),
+            Part::Text("Alpha"),
+            Part::Text("a1"),
+            Part::Text("post"),
+            Part::Text("rc2"),
+        ];
+
+        for a in &parts {
+            for b in &parts {
+                // Ordering is total and antisymmetric, only identical parts are equal
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+                assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+                for c in &parts {
+                    if a < b && b < c {
+                        assert!(a < c, "{:?} < {:?} < {:?}", a, b, c);
+                    }
+                }
+
+                // Ordering agrees with comparing versions having just these parts
+                let lhs = Version::from_parts("", vec![*a]);
+                let rhs = Version::from_parts("", vec![*b]);
+                match lhs.compare(&rhs) {
+                    Cmp::Eq => {}
+                    cmp => assert_eq!(Cmp::from(a.cmp(b)), cmp, "{:?} vs {:?}", a, b),
+                }
+            }
+        }
+    }
 
     #[test]
     fn display() {
         assert_eq!(format!("{}", Part::Number(123)), "123");
         assert_eq!(format!("{}", Part::Text("123")), "123");
     }
+
+    #[test]
+    fn owned() {
+        for part in [
+            Part::Number(0),
+            Part::Number(123),
+            Part::Text("rc"),
+            Part::Text(""),
+        ] {
+            let owned = OwnedPart::from(part);
+            assert_eq!(owned.as_part(), part);
+            assert_eq!(owned.to_string(), part.to_string());
+        }
+        assert_eq!(
+            OwnedPart::from(Part::Text("rc")),
+            OwnedPart::Text("rc".into())
+        );
+    }
 }
@@ -0,0 +1,184 @@
+//! Parser registry module.
+//!
+//! A module that provides the `ParserRegistry` struct, to select how versions are parsed by name,
+//! such as from a configuration file.
+
+use std::collections::HashMap;
+
+use crate::{CompareOptions, Scheme, Version};
+
+/// Registry of named version parsers.
+///
+/// Each parser is a named set of `CompareOptions`, selecting a manifest and scheme to parse
+/// versions with. A new registry has a parser for each version scheme, named after the scheme,
+/// and a `default` parser. Names are case-insensitive, and whitespaces are stripped.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{CompareOptions, Manifest, ParserRegistry};
+///
+/// let mut registry = ParserRegistry::new();
+/// registry.register("short", CompareOptions::default().manifest(Manifest::builder().max_depth(2).build()));
+///
+/// let a = registry.parse("1.2.3", "short").unwrap();
+/// let b = registry.parse("1.2.4", "short").unwrap();
+/// assert!(a == b);
+///
+/// assert!(registry.parse("1.0.post1", "pep440").is_some());
+/// assert!(registry.parse("1.2.3", "unknown").is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParserRegistry {
+    parsers: HashMap<String, CompareOptions>,
+}
+
+impl ParserRegistry {
+    /// Create a registry with a parser for each version scheme, and a `default` parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{ParserRegistry, Scheme};
+    ///
+    /// let registry = ParserRegistry::new();
+    ///
+    /// assert_eq!(registry.get("debian").unwrap().scheme, Scheme::Debian);
+    /// assert_eq!(registry.get("default").unwrap().scheme, Scheme::Generic);
+    /// ```
+    pub fn new() -> Self {
+        let mut registry = ParserRegistry {
+            parsers: HashMap::new(),
+        };
+        registry.register("default", CompareOptions::default());
+        for &scheme in Scheme::ALL {
+            registry.register(scheme.name(), CompareOptions::default().scheme(scheme));
+        }
+        registry
+    }
+
+    /// Register a parser with the given `name` and `options`.
+    ///
+    /// If a parser with the same name was registered, it is replaced and returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{CompareOptions, Manifest, ParserRegistry};
+    ///
+    /// let mut registry = ParserRegistry::new();
+    /// let options = CompareOptions::default().manifest(Manifest::builder().ignore_text(true).build());
+    ///
+    /// assert!(registry.register("lenient", options).is_none());
+    /// assert_eq!(registry.get("Lenient"), Some(&options));
+    /// ```
+    pub fn register<S: AsRef<str>>(
+        &mut self,
+        name: S,
+        options: CompareOptions,
+    ) -> Option<CompareOptions> {
+        self.parsers.insert(normalize(name.as_ref()), options)
+    }
+
+    /// Get the options of the parser with the given `name`.
+    ///
+    /// `None` is returned if no parser with the name is registered.
+    pub fn get<S: AsRef<str>>(&self, name: S) -> Option<&CompareOptions> {
+        self.parsers.get(&normalize(name.as_ref()))
+    }
+
+    /// Parse the given `version` string with the parser having the given `name`.
+    ///
+    /// `None` is returned if no parser with the name is registered, or if the version string is
+    /// invalid for the parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{ParserRegistry, Scheme};
+    ///
+    /// let registry = ParserRegistry::new();
+    ///
+    /// assert_eq!(registry.parse("1!1.0", "pep440").unwrap().scheme(), Scheme::Pep440);
+    /// assert!(registry.parse("1.0 final", "pep440").is_none());
+    /// ```
+    pub fn parse<'a, S: AsRef<str>>(&'a self, version: &'a str, name: S) -> Option<Version<'a>> {
+        let options = self.get(name)?;
+        match options.scheme {
+            Scheme::Generic => Version::from_manifest(version, &options.manifest),
+            scheme => Version::from_scheme(version, scheme),
+        }
+    }
+}
+
+impl Default for ParserRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Normalize a parser name, to look it up case-insensitively.
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::{Cmp, CompareOptions, Manifest, Scheme};
+
+    use super::ParserRegistry;
+
+    #[test]
+    fn builtin() {
+        let registry = ParserRegistry::new();
+
+        for (name, scheme) in [
+            ("default", Scheme::Generic),
+            ("generic", Scheme::Generic),
+            ("pep440", Scheme::Pep440),
+            ("Debian", Scheme::Debian),
+            (" semver ", Scheme::Semver),
+        ] {
+            assert_eq!(registry.get(name).unwrap().scheme, scheme);
+        }
+
+        let a = registry.parse("1.0.dev1", "pep440").unwrap();
+        let b = registry.parse("1.0a1", "pep440").unwrap();
+        assert_eq!(a.compare(&b), Cmp::Lt);
+        assert!(registry.parse("1.0", "semver").is_none());
+    }
+
+    #[test]
+    fn register() {
+        let mut registry = ParserRegistry::new();
+        let options = CompareOptions::default().manifest(
+            Manifest::builder()
+                .max_depth(2)
+                .case_sensitive(true)
+                .build(),
+        );
+
+        // Register and resolve a custom parser
+        assert!(registry.register("Custom", options).is_none());
+        assert_eq!(registry.get("custom"), Some(&options));
+        let a = registry.parse("1.RC.1", "custom").unwrap();
+        let b = registry.parse("1.rc.2", "CUSTOM").unwrap();
+        assert_eq!(a.manifest(), Some(&options.manifest));
+        assert_eq!(a.compare(&b), Cmp::Lt);
+
+        // Replace a builtin parser
+        let replaced = registry.register("default", options).unwrap();
+        assert_eq!(replaced, CompareOptions::default());
+        assert_eq!(registry.get("default"), Some(&options));
+    }
+
+    #[test]
+    fn unknown() {
+        let registry = ParserRegistry::new();
+
+        assert!(registry.get("unknown").is_none());
+        assert!(registry.get("").is_none());
+        assert!(registry.parse("1.2.3", "unknown").is_none());
+    }
+}
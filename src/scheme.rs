@@ -0,0 +1,550 @@
+//! Version scheme module, which provides the `Scheme` enum.
+//!
+//! Some version number formats follow a well defined specification with specific ordering rules,
+//! which can't be inferred using the generic best-effort approach. A `Scheme` can be used to
+//! parse and compare version numbers following such a specification.
+
+mod arch;
+mod calver;
+mod debian;
+mod gentoo;
+mod maven;
+mod pep440;
+pub(crate) mod php;
+mod rustc;
+mod semver;
+mod semver_loose;
+
+use crate::Cmp;
+
+/// Version scheme enum.
+///
+/// Each scheme specifies a version number format with its own parsing and comparison rules.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Scheme {
+    /// Generic best-effort scheme.
+    ///
+    /// This is the scheme used when no specific scheme is given, such as with `compare`.
+    Generic,
+
+    /// Python [PEP 440](https://peps.python.org/pep-0440/) scheme.
+    ///
+    /// Supports epochs (`1!2.0`), pre-releases (`2.0rc1`), post-releases (`2.0.post1`),
+    /// development releases (`2.0.dev1`) and local versions (`2.0+local`).
+    Pep440,
+
+    /// Arch Linux package version scheme, ordered like pacman's `vercmp`.
+    ///
+    /// Supports epochs (`1:2.0`) and package releases (`2.0-1`). Any version string is valid.
+    Arch,
+
+    /// Gentoo ebuild version scheme.
+    ///
+    /// Supports a letter (`2.0b`), suffixes ordered as
+    /// `_alpha < _beta < _pre < _rc < (none) < _p` and revisions (`2.0-r1`).
+    Gentoo,
+
+    /// Maven version scheme, ordered like Maven's `ComparableVersion`.
+    ///
+    /// Supports qualifiers ordered as
+    /// `alpha < beta < milestone < rc < snapshot < (release) < sp < (other)`, case insensitive.
+    /// Any version string is valid.
+    Maven,
+
+    /// Strict [Semantic Versioning 2.0.0](https://semver.org/) scheme.
+    ///
+    /// Requires exactly three numeric components (`1.2.3`), with optional pre-release
+    /// identifiers (`1.2.3-rc.1`) and build metadata (`1.2.3+build.5`).
+    Semver,
+
+    /// Debian package version scheme, ordered like `dpkg`.
+    ///
+    /// Supports epochs (`1:2.0`), revisions (`2.0-1`) and tildes sorting before anything else,
+    /// even the end of a version (`2.0~rc1 < 2.0`).
+    Debian,
+
+    /// [Calendar versioning](https://calver.org/) scheme.
+    ///
+    /// A leading date, written as `YYYYMMDD` or `YYYY.MM.DD`, is compared chronologically
+    /// (`2024.3.1 > 2024.02.28`, `20240301 == 2024.3.1`). The remainder, and versions not
+    /// starting with a date, are compared generically.
+    ///
+    /// Any leading 8 digit number with a valid month and day is a date, so `19990101` is less than
+    /// `2000` with this scheme, unlike with the generic scheme. Dates are therefore only
+    /// recognized with this scheme, rather than by the generic parser.
+    CalVer,
+
+    /// Loose semantic versioning scheme, for versions with any number of release parts.
+    ///
+    /// Unlike the generic scheme, where all separators are equal, a dash introduces a pre-release
+    /// block which is less than the release without it (`1.2-3 < 1.2`). Release parts and
+    /// pre-release blocks are compared generically, build metadata (`1.2+build`) is ignored.
+    SemverLoose,
+
+    /// PHP scheme, ordered exactly like PHP's `version_compare`.
+    ///
+    /// Special forms are ordered as `dev < alpha = a < beta = b < RC = rc < (number) < pl = p`,
+    /// matched case sensitively by prefix. Any other text is less than all of them. A separator
+    /// is inserted between letters and digits, so `1.0rc1` equals `1.0.rc.1`. Any version string
+    /// is valid.
+    ///
+    /// PHP orders a version ending with a separator below any version sharing its prefix, even
+    /// itself. This scheme orders such versions by their string instead, so comparing is
+    /// consistent both ways. Use `php_compare` for PHP's exact result.
+    Php,
+
+    /// Rust toolchain scheme, for versions like `1.75.0-beta.3` as printed by `rustc --version`.
+    ///
+    /// Requires exactly three release numbers, with an optional `-nightly`, `-beta` or `-beta.N`
+    /// channel ordered as `nightly < beta < (stable)`. A `rustc` prefix and a commit suffix like
+    /// `(abc123 2024-01-01)` are supported, nightlies are ordered by the commit date.
+    Rustc,
+}
+
+impl Scheme {
+    /// All version schemes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Scheme;
+    ///
+    /// assert_eq!(Scheme::ALL[0], Scheme::Generic);
+    /// assert!(Scheme::ALL.contains(&Scheme::Debian));
+    /// ```
+    pub const ALL: &'static [Scheme] = &[
+        Scheme::Generic,
+        Scheme::Pep440,
+        Scheme::Arch,
+        Scheme::Gentoo,
+        Scheme::Maven,
+        Scheme::Semver,
+        Scheme::Debian,
+        Scheme::CalVer,
+        Scheme::SemverLoose,
+        Scheme::Php,
+        Scheme::Rustc,
+    ];
+
+    /// Get a version scheme by it's name.
+    /// Names are case-insensitive, and whitespaces are stripped from the string.
+    /// `default` is an alias for the generic scheme.
+    /// An error is returned if the name isn't recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Scheme;
+    ///
+    /// assert_eq!(Scheme::from_name("pep440"), Ok(Scheme::Pep440));
+    /// assert_eq!(Scheme::from_name(" Debian "), Ok(Scheme::Debian));
+    /// assert_eq!(Scheme::from_name("default"), Ok(Scheme::Generic));
+    /// assert!(Scheme::from_name("abc").is_err());
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn from_name<S: AsRef<str>>(name: S) -> Result<Scheme, ()> {
+        match name.as_ref().trim().to_lowercase().as_str() {
+            "generic" | "default" => Ok(Scheme::Generic),
+            "pep440" => Ok(Scheme::Pep440),
+            "arch" => Ok(Scheme::Arch),
+            "gentoo" => Ok(Scheme::Gentoo),
+            "maven" => Ok(Scheme::Maven),
+            "semver" => Ok(Scheme::Semver),
+            "debian" => Ok(Scheme::Debian),
+            "calver" => Ok(Scheme::CalVer),
+            "semver-loose" => Ok(Scheme::SemverLoose),
+            "php" => Ok(Scheme::Php),
+            "rustc" => Ok(Scheme::Rustc),
+            _ => Err(()),
+        }
+    }
+
+    /// Get the name of this version scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Scheme;
+    ///
+    /// assert_eq!(Scheme::Generic.name(), "generic");
+    /// assert_eq!(Scheme::Pep440.name(), "pep440");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            Scheme::Generic => "generic",
+            Scheme::Pep440 => "pep440",
+            Scheme::Arch => "arch",
+            Scheme::Gentoo => "gentoo",
+            Scheme::Maven => "maven",
+            Scheme::Semver => "semver",
+            Scheme::Debian => "debian",
+            Scheme::CalVer => "calver",
+            Scheme::SemverLoose => "semver-loose",
+            Scheme::Php => "php",
+            Scheme::Rustc => "rustc",
+        }
+    }
+
+    /// Compare two version number strings to each other following this scheme.
+    ///
+    /// This compares version `a` to version `b`, and returns whether version `a` is greater, less
+    /// or equal to version `b`.
+    ///
+    /// If either version number string is invalid for this scheme an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Scheme};
+    ///
+    /// assert_eq!(Scheme::Generic.compare("1.0.post1", "1.0"), Ok(Cmp::Lt));
+    /// assert_eq!(Scheme::Pep440.compare("1.0.post1", "1.0"), Ok(Cmp::Gt));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn compare<A, B>(self, a: A, b: B) -> Result<Cmp, ()>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        match self {
+            Scheme::Generic => crate::compare(a, b),
+            Scheme::Pep440 => pep440::compare(a.as_ref(), b.as_ref()).ok_or(()),
+            Scheme::Arch => arch::compare(a.as_ref(), b.as_ref()).ok_or(()),
+            Scheme::Gentoo => gentoo::compare(a.as_ref(), b.as_ref()).ok_or(()),
+            Scheme::Maven => maven::compare(a.as_ref(), b.as_ref()).ok_or(()),
+            Scheme::Semver => semver::compare(a.as_ref(), b.as_ref()).ok_or(()),
+            Scheme::Debian => debian::compare(a.as_ref(), b.as_ref()).ok_or(()),
+            Scheme::CalVer => calver::compare(a.as_ref(), b.as_ref()).ok_or(()),
+            Scheme::SemverLoose => semver_loose::compare(a.as_ref(), b.as_ref()).ok_or(()),
+            Scheme::Php => php::compare_consistent(a.as_ref(), b.as_ref()).ok_or(()),
+            Scheme::Rustc => rustc::compare(a.as_ref(), b.as_ref()).ok_or(()),
+        }
+    }
+
+    /// Get the epoch of the given version number string for this scheme.
+    ///
+    /// The epoch is `0` if not specified. `None` is returned for schemes without epochs, or if
+    /// the version string is invalid.
+    pub(crate) fn epoch(self, version: &str) -> Option<i64> {
+        match self {
+            Scheme::Pep440 => pep440::epoch(version),
+            Scheme::Arch => Some(arch::epoch(version)),
+            Scheme::Debian => debian::epoch(version),
+            Scheme::Generic
+            | Scheme::Gentoo
+            | Scheme::Maven
+            | Scheme::Semver
+            | Scheme::CalVer
+            | Scheme::SemverLoose
+            | Scheme::Php
+            | Scheme::Rustc => None,
+        }
+    }
+
+    /// Check whether the given version number string is valid for this scheme.
+    ///
+    /// Any version string is valid for the Arch Linux, Maven and PHP schemes.
+    pub(crate) fn is_valid(self, version: &str) -> bool {
+        match self {
+            Scheme::Generic => crate::Version::from(version).is_some(),
+            Scheme::Pep440 => pep440::is_valid(version),
+            Scheme::Arch | Scheme::Maven | Scheme::Php => true,
+            Scheme::Gentoo => gentoo::is_valid(version),
+            Scheme::Semver => semver::is_valid(version),
+            Scheme::Debian => debian::is_valid(version),
+            Scheme::CalVer => calver::is_valid(version),
+            Scheme::SemverLoose => semver_loose::is_valid(version),
+            Scheme::Rustc => rustc::is_valid(version),
+        }
+    }
+}
+
+/// Check whether the given version number string is valid for the given version `scheme`.
+///
+/// This checks whether the version string fully conforms to the scheme, such as having exactly
+/// three numeric components for `Scheme::Semver`. The version is parsed to check it, generically
+/// for the generic, calendar and loose semantic versioning schemes, but only the result is
+/// returned. Any version string is valid for the Arch Linux, Maven and PHP schemes.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{is_valid, Scheme};
+///
+/// assert!(is_valid("1.2", Scheme::Generic));
+/// assert!(!is_valid("1.2", Scheme::Semver));
+/// assert!(is_valid("1.2.0", Scheme::Semver));
+/// ```
+pub fn is_valid(version: &str, scheme: Scheme) -> bool {
+    scheme.is_valid(version)
+}
+
+/// Validate the given version number string for the given version `scheme`.
+///
+/// This is the same as `is_valid`, but returns an error if the version string is invalid, to
+/// easily propagate it with `?`.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{validate, Scheme};
+///
+/// assert_eq!(validate("1.2.3-rc.1", Scheme::Semver), Ok(()));
+/// assert_eq!(validate("1.2-rc.1", Scheme::Semver), Err(()));
+/// ```
+#[allow(clippy::result_unit_err)]
+pub fn validate(version: &str, scheme: Scheme) -> Result<(), ()> {
+    if scheme.is_valid(version) {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+/// Guess the version scheme of the given version number string.
+///
+/// This is a best-effort heuristic based on scheme specific markers, to help selecting a scheme
+/// for unknown input. The following rules are tried in order:
+///
+/// * `Scheme::Debian` for a tilde (`1.0~rc1`) or an epoch (`1:1.0`)
+/// * `Scheme::Pep440` for an epoch (`1!1.0`), post-release (`1.0.post1`) or development release
+///   (`1.0.dev1`)
+/// * `Scheme::Semver` for a valid semantic version (`1.2.3`, `1.2.3-rc.1+build.5`)
+/// * `Scheme::Pep440` for any other valid PEP 440 version that isn't just numbers (`1.0rc1`)
+/// * `Scheme::Generic` otherwise
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{detect_scheme, Scheme};
+///
+/// assert_eq!(detect_scheme("1!1.0.post1"), Scheme::Pep440);
+/// assert_eq!(detect_scheme("1.0~rc1"), Scheme::Debian);
+/// assert_eq!(detect_scheme("1.2.3+build.5"), Scheme::Semver);
+/// assert_eq!(detect_scheme("1.0"), Scheme::Generic);
+/// ```
+pub fn detect_scheme(version: &str) -> Scheme {
+    // Debian specific markers
+    let epoch = version.split_once(':').map_or(false, |(epoch, _)| {
+        !epoch.is_empty() && epoch.bytes().all(|c| c.is_ascii_digit())
+    });
+    if (version.contains('~') || epoch) && debian::is_valid(version) {
+        return Scheme::Debian;
+    }
+
+    // PEP 440 specific markers
+    let lower = version.to_lowercase();
+    if (lower.contains('!') || lower.contains("post") || lower.contains("dev"))
+        && pep440::is_valid(version)
+    {
+        return Scheme::Pep440;
+    }
+
+    if semver::is_valid(version) {
+        return Scheme::Semver;
+    }
+
+    if !version.bytes().all(|c| c.is_ascii_digit() || c == b'.') && pep440::is_valid(version) {
+        return Scheme::Pep440;
+    }
+
+    Scheme::Generic
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::test::COMBIS;
+    use crate::Cmp;
+
+    use super::{detect_scheme, is_valid, validate, Scheme};
+
+    #[test]
+    fn compare_generic() {
+        // Compare each version in the version set having the default manifest
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            assert_eq!(Scheme::Generic.compare(entry.0, entry.1), Ok(entry.2));
+        }
+    }
+
+    #[test]
+    fn compare_pep440() {
+        assert_eq!(Scheme::Pep440.compare("1.0", "1.0.0"), Ok(Cmp::Eq));
+        assert_eq!(Scheme::Pep440.compare("1.0a1", "1.0"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Pep440.compare("1.0", "1.0.post1"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Pep440.compare("1.0", "1.0 final"), Err(()));
+    }
+
+    #[test]
+    fn compare_arch() {
+        assert_eq!(Scheme::Arch.compare("1.0a", "1.0"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Arch.compare("1.0.1", "1.0"), Ok(Cmp::Gt));
+        assert_eq!(Scheme::Arch.compare("1.0-1", "1.0-2"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Arch.compare("1:1.0", "2.0"), Ok(Cmp::Gt));
+    }
+
+    #[test]
+    fn compare_gentoo() {
+        assert_eq!(Scheme::Gentoo.compare("1.0_alpha", "1.0_beta"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Gentoo.compare("1.0_rc1", "1.0"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Gentoo.compare("1.0", "1.0_p1"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Gentoo.compare("1.0-r1", "1.0"), Ok(Cmp::Gt));
+        assert_eq!(Scheme::Gentoo.compare("1.0", "1.0 final"), Err(()));
+    }
+
+    #[test]
+    fn compare_maven() {
+        assert_eq!(Scheme::Maven.compare("1.0-alpha-1", "1.0"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Maven.compare("1.0-SNAPSHOT", "1.0"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Maven.compare("1.0", "1.0-sp"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Maven.compare("1.0", "1.0.0-ga"), Ok(Cmp::Eq));
+    }
+
+    #[test]
+    fn compare_semver() {
+        assert_eq!(Scheme::Semver.compare("1.0.0-rc.1", "1.0.0"), Ok(Cmp::Lt));
+        assert_eq!(
+            Scheme::Semver.compare("1.0.0-beta.11", "1.0.0-beta.2"),
+            Ok(Cmp::Gt)
+        );
+        assert_eq!(Scheme::Semver.compare("1.0.0+a", "1.0.0+b"), Ok(Cmp::Eq));
+        assert_eq!(Scheme::Semver.compare("1.0", "1.0.0"), Err(()));
+    }
+
+    #[test]
+    fn compare_debian() {
+        assert_eq!(Scheme::Debian.compare("1.0~rc1", "1.0"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Debian.compare("1:0.9", "1.0"), Ok(Cmp::Gt));
+        assert_eq!(Scheme::Debian.compare("1.0-1", "1.0-2"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Debian.compare("1.0", "1.0 final"), Err(()));
+    }
+
+    #[test]
+    fn compare_calver() {
+        assert_eq!(
+            Scheme::CalVer.compare("2024.3.1", "2024.02.28"),
+            Ok(Cmp::Gt)
+        );
+        assert_eq!(
+            Scheme::CalVer.compare("20240301", "2024.02.28"),
+            Ok(Cmp::Gt)
+        );
+        assert_eq!(
+            Scheme::CalVer.compare("2023.12.31", "20240101"),
+            Ok(Cmp::Lt)
+        );
+        assert_eq!(Scheme::CalVer.compare("1.2.3", "1.10"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::CalVer.compare("2024.3.1", "abc"), Err(()));
+    }
+
+    #[test]
+    fn compare_semver_loose() {
+        // A dash introduces a pre-release, unlike with the generic scheme
+        assert_eq!(Scheme::SemverLoose.compare("1.2-3", "1.2"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Generic.compare("1.2-3", "1.2"), Ok(Cmp::Gt));
+
+        // Dots only separate release parts, like with the generic scheme
+        for (a, b) in [("1.2.3", "1.2"), ("1.2.3.4", "1.2.3"), ("1.2_3", "1.2.2")] {
+            assert_eq!(Scheme::SemverLoose.compare(a, b), Ok(Cmp::Gt));
+            assert_eq!(Scheme::Generic.compare(a, b), Ok(Cmp::Gt));
+        }
+
+        assert_eq!(
+            Scheme::SemverLoose.compare("1.2-3.4_5", "1.2-3.4.5"),
+            Ok(Cmp::Eq)
+        );
+        assert_eq!(
+            Scheme::SemverLoose.compare("1.2-rc.1", "1.2-rc.2"),
+            Ok(Cmp::Lt)
+        );
+        assert_eq!(Scheme::SemverLoose.compare("1.2", "1.2-"), Err(()));
+    }
+
+    #[test]
+    fn compare_php() {
+        assert_eq!(Scheme::Php.compare("1.0-dev", "1.0a1"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Php.compare("1.0RC1", "1.0rc1"), Ok(Cmp::Eq));
+        assert_eq!(Scheme::Php.compare("1.0", "1.0pl1"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Php.compare("5.2", "5.2.0"), Ok(Cmp::Lt));
+        assert_eq!(Scheme::Php.compare("1.0 final", "1.0"), Ok(Cmp::Lt));
+
+        // Versions ending with a separator are ordered consistently, unlike in PHP
+        assert_eq!(Scheme::Php.compare("1.0.", "1.0."), Ok(Cmp::Eq));
+        let version = crate::Version::from_scheme("1.0.", Scheme::Php).unwrap();
+        assert!(version == version);
+    }
+
+    #[test]
+    fn compare_rustc() {
+        assert_eq!(
+            Scheme::Rustc.compare("1.75.0-nightly", "1.75.0-beta.1"),
+            Ok(Cmp::Lt)
+        );
+        assert_eq!(
+            Scheme::Rustc.compare("1.75.0-beta.1", "1.75.0"),
+            Ok(Cmp::Lt)
+        );
+        assert_eq!(
+            Scheme::Rustc.compare(
+                "1.75.0-nightly (abc 2024-01-02)",
+                "1.75.0-nightly (def 2024-01-01)"
+            ),
+            Ok(Cmp::Gt)
+        );
+        assert_eq!(Scheme::Rustc.compare("1.75", "1.75.0"), Err(()));
+    }
+
+    #[test]
+    fn name() {
+        for &scheme in Scheme::ALL {
+            assert_eq!(Scheme::from_name(scheme.name()), Ok(scheme));
+            assert_eq!(Scheme::from_name(scheme.name().to_uppercase()), Ok(scheme));
+        }
+
+        assert_eq!(Scheme::from_name("default"), Ok(Scheme::Generic));
+        assert!(Scheme::from_name("").is_err());
+        assert!(Scheme::from_name("pep 440").is_err());
+    }
+
+    #[test]
+    fn valid() {
+        // Strict semantic versions require three components
+        assert!(is_valid("1.2", Scheme::Generic));
+        assert!(!is_valid("1.2", Scheme::Semver));
+        assert_eq!(validate("1.2", Scheme::Generic), Ok(()));
+        assert_eq!(validate("1.2", Scheme::Semver), Err(()));
+
+        for (version, scheme, valid) in [
+            ("1.2.3", Scheme::Semver, true),
+            ("v1.2.3", Scheme::Semver, false),
+            ("1.0.post1", Scheme::Pep440, true),
+            ("1.0 final", Scheme::Pep440, false),
+            ("1.0~rc1", Scheme::Debian, true),
+            ("abc", Scheme::Generic, false),
+            ("abc", Scheme::Arch, true),
+            ("abc", Scheme::Php, true),
+        ] {
+            assert_eq!(is_valid(version, scheme), valid, "{} {:?}", version, scheme);
+            assert_eq!(validate(version, scheme).is_ok(), valid);
+            assert_eq!(
+                crate::Version::from_scheme(version, scheme).is_some(),
+                valid
+            );
+        }
+    }
+
+    #[test]
+    fn detect() {
+        assert_eq!(detect_scheme("1!1.0.post1"), Scheme::Pep440);
+        assert_eq!(detect_scheme("1.0.dev3"), Scheme::Pep440);
+        assert_eq!(detect_scheme("1.0rc1"), Scheme::Pep440);
+        assert_eq!(detect_scheme("1.0+local.1"), Scheme::Pep440);
+        assert_eq!(detect_scheme("1.0~rc1"), Scheme::Debian);
+        assert_eq!(detect_scheme("2:1.0-1"), Scheme::Debian);
+        assert_eq!(detect_scheme("1.2.3"), Scheme::Semver);
+        assert_eq!(detect_scheme("1.2.3-rc.1+build.5"), Scheme::Semver);
+        assert_eq!(detect_scheme("1.0"), Scheme::Generic);
+        assert_eq!(detect_scheme("1.0 final"), Scheme::Generic);
+    }
+}
@@ -0,0 +1,242 @@
+//! Arch Linux package version scheme.
+//!
+//! Implements the ordering of pacman's `vercmp`, comparing `epoch:pkgver-pkgrel` versions using
+//! the `rpmvercmp` algorithm.
+
+use std::cmp::Ordering;
+
+use crate::Cmp;
+
+/// Split a version string into its epoch, version and optional release.
+///
+/// The epoch defaults to `0` if not specified.
+fn parse_evr(evr: &str) -> (&str, &str, Option<&str>) {
+    // Take the epoch, only digits followed by a colon
+    let digits = evr.bytes().take_while(u8::is_ascii_digit).count();
+    let (epoch, version) = match evr[digits..].strip_prefix(':') {
+        Some(version) if digits > 0 => (&evr[..digits], version),
+        Some(version) => ("0", version),
+        None => ("0", evr),
+    };
+
+    // Take the release after the last dash
+    match version.rsplit_once('-') {
+        Some((version, release)) => (epoch, version, Some(release)),
+        None => (epoch, version, None),
+    }
+}
+
+/// Get the epoch of the given version string, `0` if not specified.
+pub(crate) fn epoch(version: &str) -> i64 {
+    let (epoch, _, _) = parse_evr(version);
+    epoch.parse().unwrap_or(i64::MAX)
+}
+
+/// Compare two version strings using the `rpmvercmp` algorithm.
+///
+/// Both strings are split into alternating alphabetic and numeric segments, separated by any
+/// other characters. Numeric segments are always newer than alphabetic segments.
+fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut one, mut two) = (0, 0);
+
+    while one < a.len() && two < b.len() {
+        // Skip separators
+        let (sep_one, sep_two) = (one, two);
+        while one < a.len() && !a[one].is_ascii_alphanumeric() {
+            one += 1;
+        }
+        while two < b.len() && !b[two].is_ascii_alphanumeric() {
+            two += 1;
+        }
+        if one >= a.len() || two >= b.len() {
+            break;
+        }
+
+        // Separator lengths must be equal
+        if one - sep_one != two - sep_two {
+            return (one - sep_one).cmp(&(two - sep_two));
+        }
+
+        // Grab a completely numeric or alphabetic segment from both
+        let numeric = a[one].is_ascii_digit();
+        let segment = |s: &[u8], at: usize| {
+            let len = s[at..]
+                .iter()
+                .take_while(|c| {
+                    if numeric {
+                        c.is_ascii_digit()
+                    } else {
+                        c.is_ascii_alphabetic()
+                    }
+                })
+                .count();
+            at + len
+        };
+        let (end_one, end_two) = (segment(a, one), segment(b, two));
+
+        // Segments of different types, numeric segments are always newer
+        if end_two == two {
+            return if numeric {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+
+        // Compare numbers by magnitude, ignoring leading zeros
+        let (mut seg_one, mut seg_two) = (&a[one..end_one], &b[two..end_two]);
+        if numeric {
+            while seg_one.first() == Some(&b'0') {
+                seg_one = &seg_one[1..];
+            }
+            while seg_two.first() == Some(&b'0') {
+                seg_two = &seg_two[1..];
+            }
+            match seg_one.len().cmp(&seg_two.len()) {
+                Ordering::Equal => {}
+                ord => return ord,
+            }
+        }
+        match seg_one.cmp(seg_two) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+
+        one = end_one;
+        two = end_two;
+    }
+
+    // All segments are equal, though separators might differ
+    if one >= a.len() && two >= b.len() {
+        return Ordering::Equal;
+    }
+
+    // A remaining alphabetic segment never beats an empty string
+    let one_alpha = a.get(one).map_or(false, u8::is_ascii_alphabetic);
+    let two_alpha = b.get(two).map_or(false, u8::is_ascii_alphabetic);
+    if (one >= a.len() && !two_alpha) || one_alpha {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+/// Compare two Arch Linux package version strings, like `vercmp` does.
+///
+/// The release is only compared if both versions have one. Any version string is valid.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    if a == b {
+        return Some(Cmp::Eq);
+    }
+
+    let (epoch_a, version_a, release_a) = parse_evr(a);
+    let (epoch_b, version_b, release_b) = parse_evr(b);
+
+    let ord = rpmvercmp(epoch_a, epoch_b)
+        .then_with(|| rpmvercmp(version_a, version_b))
+        .then_with(|| match (release_a, release_b) {
+            (Some(release_a), Some(release_b)) => rpmvercmp(release_a, release_b),
+            _ => Ordering::Equal,
+        });
+    Some(ord.into())
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{compare, parse_evr};
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_evr("1.0"), ("0", "1.0", None));
+        assert_eq!(parse_evr("1.0-2"), ("0", "1.0", Some("2")));
+        assert_eq!(parse_evr("3:1.0-2"), ("3", "1.0", Some("2")));
+        assert_eq!(parse_evr(":1.0-a-2"), ("0", "1.0-a", Some("2")));
+        assert_eq!(parse_evr("a:1.0"), ("0", "a:1.0", None));
+    }
+
+    #[test]
+    fn compare_vercmp() {
+        // Test cases from the pacman vercmp test suite
+        let cases = [
+            // All similar length, no pkgrel
+            ("1.5.0", "1.5.0", Cmp::Eq),
+            ("1.5.1", "1.5.0", Cmp::Gt),
+            // Mixed length
+            ("1.5.1", "1.5", Cmp::Gt),
+            // With pkgrel, simple
+            ("1.5.0-1", "1.5.0-1", Cmp::Eq),
+            ("1.5.0-1", "1.5.0-2", Cmp::Lt),
+            ("1.5.0-1", "1.5.1-1", Cmp::Lt),
+            ("1.5.0-2", "1.5.1-1", Cmp::Lt),
+            // With pkgrel, mixed lengths
+            ("1.5-1", "1.5.1-1", Cmp::Lt),
+            ("1.5-2", "1.5.1-1", Cmp::Lt),
+            ("1.5-2", "1.5.1-2", Cmp::Lt),
+            // Mixed pkgrel inclusion
+            ("1.5", "1.5-1", Cmp::Eq),
+            ("1.5-1", "1.5", Cmp::Eq),
+            ("1.1-1", "1.1", Cmp::Eq),
+            ("1.0-1", "1.1", Cmp::Lt),
+            ("1.1-1", "1.0", Cmp::Gt),
+            // Alphanumeric versions
+            ("1.5b-1", "1.5-1", Cmp::Lt),
+            ("1.5b", "1.5", Cmp::Lt),
+            ("1.5b-1", "1.5", Cmp::Lt),
+            ("1.5b", "1.5.1", Cmp::Lt),
+            // From the manpage
+            ("1.0a", "1.0alpha", Cmp::Lt),
+            ("1.0alpha", "1.0b", Cmp::Lt),
+            ("1.0b", "1.0beta", Cmp::Lt),
+            ("1.0beta", "1.0rc", Cmp::Lt),
+            ("1.0rc", "1.0", Cmp::Lt),
+            // Alpha-dotted versions
+            ("1.5.a", "1.5", Cmp::Gt),
+            ("1.5.b", "1.5.a", Cmp::Gt),
+            ("1.5.1", "1.5.b", Cmp::Gt),
+            // Alpha dots and dashes
+            ("1.5.b-1", "1.5.b", Cmp::Eq),
+            ("1.5-1", "1.5.b", Cmp::Lt),
+            // Same or similar content, differing separators
+            ("2.0", "2_0", Cmp::Eq),
+            ("2.0_a", "2_0.a", Cmp::Eq),
+            ("2.0a", "2.0.a", Cmp::Lt),
+            ("2___a", "2_a", Cmp::Gt),
+            // Epoch included version comparisons
+            ("0:1.0", "0:1.0", Cmp::Eq),
+            ("0:1.0", "0:1.1", Cmp::Lt),
+            ("1:1.0", "0:1.0", Cmp::Gt),
+            ("1:1.0", "0:1.1", Cmp::Gt),
+            ("1:1.0", "2:1.1", Cmp::Lt),
+            // Epoch and sometimes present pkgrel
+            ("1:1.0", "0:1.0-1", Cmp::Gt),
+            ("1:1.0-1", "0:1.1-1", Cmp::Gt),
+            // Epoch included on one version
+            ("0:1.0", "1.0", Cmp::Eq),
+            ("0:1.0", "1.1", Cmp::Lt),
+            ("0:1.1", "1.0", Cmp::Gt),
+            ("1:1.0", "1.0", Cmp::Gt),
+            ("1:1.0", "1.1", Cmp::Gt),
+            ("1:1.1", "1.1", Cmp::Gt),
+        ];
+
+        for (a, b, cmp) in cases {
+            assert_eq!(
+                compare(a, b),
+                Some(cmp),
+                "Testing that {} is {} {}",
+                a,
+                cmp.sign(),
+                b
+            );
+            assert_eq!(compare(b, a), Some(cmp.flip()));
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Calendar versioning scheme.
+//!
+//! Implements [CalVer](https://calver.org/) versions starting with a date, either written as
+//! `YYYYMMDD` or as `YYYY.MM.DD`, such as `20240301` or `2024.3.1-rc1`.
+//!
+//! This is an opt-in scheme rather than a date part recognized by the generic parser. Any 8 digit
+//! number with a valid month and day is read as a date, so generic versions like `19990101` would
+//! silently change order against plain numbers. Only versions compared with this scheme are
+//! affected.
+
+use crate::Cmp;
+
+/// A date, as year, month and day.
+type Date = (u32, u32, u32);
+
+/// Split the leading date from the given version string.
+///
+/// The date is either written as `YYYYMMDD`, or as `YYYY.MM.DD` with one or two digit months and
+/// days. The date must be followed by the end of the string or a non-digit, and the month and day
+/// must be in range. The remainder after the date is returned as well.
+fn parse_date(version: &str) -> Option<(Date, &str)> {
+    let digits = |s: &str| s.bytes().take_while(u8::is_ascii_digit).count();
+
+    let (date, rest) = match digits(version) {
+        // Compact date, like `20240301`
+        8 => {
+            let date = (
+                version[..4].parse().ok()?,
+                version[4..6].parse().ok()?,
+                version[6..8].parse().ok()?,
+            );
+            (date, &version[8..])
+        }
+
+        // Dotted date, like `2024.3.1`
+        4 => {
+            let mut rest = &version[4..];
+            let mut fields = [0; 2];
+            for field in fields.iter_mut() {
+                rest = rest.strip_prefix('.')?;
+                let len = digits(rest);
+                if len == 0 || len > 2 {
+                    return None;
+                }
+                *field = rest[..len].parse().ok()?;
+                rest = &rest[len..];
+            }
+            ((version[..4].parse().ok()?, fields[0], fields[1]), rest)
+        }
+
+        _ => return None,
+    };
+
+    if !(1..=12).contains(&date.1) || !(1..=31).contains(&date.2) {
+        return None;
+    }
+    Some((date, rest))
+}
+
+/// Normalize the given version string, writing a leading date as `YYYY.M.D`.
+///
+/// Version strings not starting with a date are returned as is.
+fn normalize(version: &str) -> String {
+    match parse_date(version) {
+        Some(((year, month, day), rest)) => format!("{}.{}.{}{}", year, month, day, rest),
+        None => version.into(),
+    }
+}
+
+/// Check whether the given version string is a valid calendar version.
+///
+/// Versions not starting with a date are valid if they're valid generic versions.
+pub(crate) fn is_valid(version: &str) -> bool {
+    crate::Version::from(&normalize(version)).is_some()
+}
+
+/// Compare two calendar version strings.
+///
+/// Leading dates are compared chronologically, the remainder is compared generically. Versions
+/// not starting with a date are compared generically.
+///
+/// `None` is returned if either version string is invalid.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    crate::compare(normalize(a), normalize(b)).ok()
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{compare, parse_date};
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_date("20240301"), Some(((2024, 3, 1), "")));
+        assert_eq!(parse_date("2024.3.1"), Some(((2024, 3, 1), "")));
+        assert_eq!(parse_date("2024.02.28-rc1"), Some(((2024, 2, 28), "-rc1")));
+        assert_eq!(parse_date("20240301.2"), Some(((2024, 3, 1), ".2")));
+
+        // Not a date
+        for version in [
+            "",
+            "1.2.3",
+            "2024",
+            "2024.3",
+            "2024.3.",
+            "2024.123.1",
+            "2024.13.1",
+            "2024.0.1",
+            "2024.1.32",
+            "20241301",
+            "202403011",
+            "v2024.3.1",
+        ] {
+            assert!(
+                parse_date(version).is_none(),
+                "{} must not be a date",
+                version
+            );
+        }
+    }
+
+    #[test]
+    fn compare_dates() {
+        // Cross-month, cross-year and mixed date formats
+        assert_eq!(compare("2024.3.1", "2024.02.28"), Some(Cmp::Gt));
+        assert_eq!(compare("20240301", "2024.02.28"), Some(Cmp::Gt));
+        assert_eq!(compare("2023.12.31", "2024.01.01"), Some(Cmp::Lt));
+        assert_eq!(compare("20231231", "20240101"), Some(Cmp::Lt));
+        assert_eq!(compare("20240301", "2024.03.01"), Some(Cmp::Eq));
+
+        // Remainder after the date
+        assert_eq!(compare("2024.3.1.1", "2024.3.1"), Some(Cmp::Gt));
+        assert_eq!(compare("20240301-2", "2024.3.1-10"), Some(Cmp::Lt));
+    }
+
+    #[test]
+    fn compare_other() {
+        // Non-date versions compare generically
+        assert_eq!(compare("1.2.3", "1.10"), Some(Cmp::Lt));
+        assert_eq!(compare("12345678", "1.2"), Some(Cmp::Gt));
+        assert_eq!(compare("2024.13.1", "2024.2.1"), Some(Cmp::Gt));
+        assert_eq!(compare("abc", "1.0"), None);
+    }
+
+    #[test]
+    fn compare_false_positive() {
+        // An 8 digit number with a valid month and day is a date, unlike with generic comparison
+        assert_eq!(parse_date("19990101"), Some(((1999, 1, 1), "")));
+        assert_eq!(compare("19990101", "2000"), Some(Cmp::Lt));
+        assert_eq!(crate::compare("19990101", "2000"), Ok(Cmp::Gt));
+
+        // Without a valid month or day it isn't a date
+        assert_eq!(compare("19991301", "2000"), Some(Cmp::Gt));
+    }
+}
@@ -0,0 +1,216 @@
+//! Debian package version scheme.
+//!
+//! Implements parsing and ordering as specified in the
+//! [Debian Policy Manual](https://www.debian.org/doc/debian-policy/ch-controlfields.html#version),
+//! comparing `[epoch:]upstream_version[-debian_revision]` versions like `dpkg` does.
+
+use std::cmp::Ordering;
+
+use crate::Cmp;
+
+/// A parsed Debian version.
+#[derive(Debug, PartialEq, Eq)]
+struct Debian<'a> {
+    epoch: &'a str,
+    upstream: &'a str,
+    revision: &'a str,
+}
+
+/// Parse the given version string as Debian version.
+fn parse(version: &str) -> Option<Debian<'_>> {
+    // Take the epoch, which must be numeric
+    let (epoch, rest) = match version.split_once(':') {
+        Some((epoch, rest)) => {
+            if epoch.is_empty() || !epoch.bytes().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            (epoch, rest)
+        }
+        None => ("", version),
+    };
+
+    // Take the revision after the last dash
+    let (upstream, revision) = match rest.rsplit_once('-') {
+        Some((upstream, revision)) => {
+            let valid = |c: u8| c.is_ascii_alphanumeric() || b"+.~".contains(&c);
+            if revision.is_empty() || !revision.bytes().all(valid) {
+                return None;
+            }
+            (upstream, revision)
+        }
+        None => (rest, ""),
+    };
+
+    // The upstream version must start with a digit
+    let valid = |c: u8| c.is_ascii_alphanumeric() || b"+.~-:".contains(&c);
+    if !upstream.starts_with(|c: char| c.is_ascii_digit()) || !upstream.bytes().all(valid) {
+        return None;
+    }
+
+    Some(Debian {
+        epoch,
+        upstream,
+        revision,
+    })
+}
+
+/// Get the sort weight of a character in a non-digit part.
+///
+/// The end of a part sorts before everything but a tilde, letters sort before other characters.
+fn order(c: Option<u8>) -> i32 {
+    match c {
+        None => 0,
+        Some(b'~') => -1,
+        Some(c) if c.is_ascii_digit() => 0,
+        Some(c) if c.is_ascii_alphabetic() => c as i32,
+        Some(c) => c as i32 + 256,
+    }
+}
+
+/// Compare two version strings using the `dpkg` algorithm.
+///
+/// Both strings are compared in alternating non-digit and digit parts. Non-digit parts are
+/// compared character by character using `order`, digit parts are compared as integers.
+fn verrevcmp(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    let is_digit = |s: &[u8], at: usize| s.get(at).map_or(false, u8::is_ascii_digit);
+
+    while i < a.len() || j < b.len() {
+        // Compare the non-digit part
+        while (i < a.len() && !is_digit(a, i)) || (j < b.len() && !is_digit(b, j)) {
+            let ord = order(a.get(i).copied()).cmp(&order(b.get(j).copied()));
+            if ord != Ordering::Equal {
+                return ord;
+            }
+            i += 1;
+            j += 1;
+        }
+
+        // Compare the digit part, ignoring leading zeros
+        while a.get(i) == Some(&b'0') {
+            i += 1;
+        }
+        while b.get(j) == Some(&b'0') {
+            j += 1;
+        }
+        let mut first_diff = Ordering::Equal;
+        while is_digit(a, i) && is_digit(b, j) {
+            if first_diff == Ordering::Equal {
+                first_diff = a[i].cmp(&b[j]);
+            }
+            i += 1;
+            j += 1;
+        }
+        if is_digit(a, i) {
+            return Ordering::Greater;
+        }
+        if is_digit(b, j) {
+            return Ordering::Less;
+        }
+        if first_diff != Ordering::Equal {
+            return first_diff;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Check whether the given version string is a valid Debian version.
+pub(crate) fn is_valid(version: &str) -> bool {
+    parse(version).is_some()
+}
+
+/// Get the epoch of the given Debian version string, `0` if not specified.
+///
+/// `None` is returned if the version string is not a valid Debian version.
+pub(crate) fn epoch(version: &str) -> Option<i64> {
+    let epoch = parse(version)?.epoch;
+    Some(if epoch.is_empty() {
+        0
+    } else {
+        epoch.parse().unwrap_or(i64::MAX)
+    })
+}
+
+/// Compare two Debian version strings.
+///
+/// `None` is returned if either version string is not a valid Debian version.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    Some(compare_parsed(&parse(a)?, &parse(b)?).into())
+}
+
+/// Compare two parsed Debian versions.
+fn compare_parsed(a: &Debian, b: &Debian) -> Ordering {
+    verrevcmp(a.epoch, b.epoch)
+        .then_with(|| verrevcmp(a.upstream, b.upstream))
+        .then_with(|| verrevcmp(a.revision, b.revision))
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{compare, parse, Debian};
+
+    #[test]
+    fn parse_valid() {
+        assert_eq!(
+            parse("1:2.0~rc1-1ubuntu2"),
+            Some(Debian {
+                epoch: "1",
+                upstream: "2.0~rc1",
+                revision: "1ubuntu2",
+            }),
+        );
+        assert_eq!(parse("1.0-2-3").unwrap().upstream, "1.0-2");
+        assert_eq!(parse("1.0").unwrap().revision, "");
+
+        // Invalid versions
+        for version in ["", "a1.0", ":1.0", "a:1.0", "1.0-", "1.0-a_b", "1.0 final"] {
+            assert!(parse(version).is_none(), "{} must be invalid", version);
+        }
+    }
+
+    #[test]
+    fn compare_ordering() {
+        let versions = [
+            "0.9",
+            "1.0~~",
+            "1.0~~a",
+            "1.0~",
+            "1.0~rc1",
+            "1.0",
+            "1.0-1",
+            "1.0-1ubuntu1",
+            "1.0-2",
+            "1.0a",
+            "1.0+dfsg",
+            "1.0.1",
+            "1.10",
+            "1:0.1",
+        ];
+
+        for (i, a) in versions.iter().enumerate() {
+            for (j, b) in versions.iter().enumerate() {
+                assert_eq!(
+                    compare(a, b),
+                    Some(i.cmp(&j).into()),
+                    "Testing that {} is {} {}",
+                    a,
+                    Cmp::from(i.cmp(&j)).sign(),
+                    b,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compare_equal() {
+        assert_eq!(compare("0:1.0", "1.0"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0", "1.0-0"), Some(Cmp::Eq));
+        assert_eq!(compare("1.01", "1.1"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0", "1.00"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0", "1.0 final"), None);
+    }
+}
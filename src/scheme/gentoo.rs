@@ -0,0 +1,241 @@
+//! Gentoo ebuild version scheme.
+//!
+//! Implements parsing and ordering as specified in the Gentoo
+//! [Package Manager Specification](https://projects.gentoo.org/pms/latest/pms.html#version-comparison),
+//! for versions like `1.2.3b_rc4_p1-r2`.
+
+use std::cmp::Ordering;
+
+use crate::Cmp;
+
+/// Version suffixes, in order.
+const SUFFIXES: &[(&str, u8)] = &[("alpha", 0), ("beta", 1), ("pre", 2), ("rc", 3), ("p", 4)];
+
+/// The weight of the `_p` suffix, which sorts after no suffix.
+const SUFFIX_P: u8 = 4;
+
+/// A parsed Gentoo version.
+#[derive(Debug, PartialEq, Eq)]
+struct Gentoo<'a> {
+    numbers: Vec<&'a str>,
+    letter: Option<u8>,
+    suffixes: Vec<(u8, &'a str)>,
+    revision: &'a str,
+}
+
+/// Parse the given version string as Gentoo version.
+fn parse(version: &str) -> Option<Gentoo<'_>> {
+    let mut rest = version;
+
+    // Take the numeric components
+    let mut numbers = vec![];
+    loop {
+        let (number, tail) = take_digits(rest);
+        if number.is_empty() {
+            return None;
+        }
+        numbers.push(number);
+        rest = tail;
+        match rest.strip_prefix('.') {
+            Some(tail) if tail.starts_with(|c: char| c.is_ascii_digit()) => rest = tail,
+            _ => break,
+        }
+    }
+
+    // Take the optional letter
+    let letter = match rest.as_bytes().first() {
+        Some(c) if c.is_ascii_lowercase() => {
+            rest = &rest[1..];
+            Some(*c)
+        }
+        _ => None,
+    };
+
+    // Take the suffixes
+    let mut suffixes = vec![];
+    while let Some(tail) = rest.strip_prefix('_') {
+        let (suffix, weight) = SUFFIXES.iter().find(|(s, _)| tail.starts_with(s))?;
+        let (number, tail) = take_digits(&tail[suffix.len()..]);
+        suffixes.push((*weight, number));
+        rest = tail;
+    }
+
+    // Take the optional revision
+    let revision = match rest.strip_prefix("-r") {
+        Some(tail) => {
+            let (number, tail) = take_digits(tail);
+            if number.is_empty() {
+                return None;
+            }
+            rest = tail;
+            number
+        }
+        None => "",
+    };
+
+    // Nothing may be left
+    if !rest.is_empty() {
+        return None;
+    }
+
+    Some(Gentoo {
+        numbers,
+        letter,
+        suffixes,
+        revision,
+    })
+}
+
+/// Take leading digits from the given string, returning the digits and the remainder.
+fn take_digits(s: &str) -> (&str, &str) {
+    s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()))
+}
+
+/// Compare two strings of digits as integers, an empty string is zero.
+fn compare_int(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Check whether the given version string is a valid Gentoo version.
+pub(crate) fn is_valid(version: &str) -> bool {
+    parse(version).is_some()
+}
+
+/// Compare two Gentoo version strings.
+///
+/// `None` is returned if either version string is not a valid Gentoo version.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    Some(compare_parsed(&parse(a)?, &parse(b)?).into())
+}
+
+/// Compare two parsed Gentoo versions.
+fn compare_parsed(a: &Gentoo, b: &Gentoo) -> Ordering {
+    compare_numbers(&a.numbers, &b.numbers)
+        .then_with(|| a.letter.cmp(&b.letter))
+        .then_with(|| compare_suffixes(&a.suffixes, &b.suffixes))
+        .then_with(|| compare_int(a.revision, b.revision))
+}
+
+/// Compare numeric components.
+///
+/// The first component is compared as integer. Following components with a leading zero are
+/// compared as strings with trailing zeros stripped. If all shared components are equal, the
+/// version with more components is greater.
+fn compare_numbers(a: &[&str], b: &[&str]) -> Ordering {
+    for (i, (lhs, rhs)) in a.iter().zip(b).enumerate() {
+        let ord = if i > 0 && (lhs.starts_with('0') || rhs.starts_with('0')) {
+            lhs.trim_end_matches('0').cmp(rhs.trim_end_matches('0'))
+        } else {
+            compare_int(lhs, rhs)
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+/// Compare suffixes.
+///
+/// If all shared suffixes are equal, the version with an extra `_p` suffix is greater, and the
+/// version with any other extra suffix is less.
+fn compare_suffixes(a: &[(u8, &str)], b: &[(u8, &str)]) -> Ordering {
+    for ((lhs, lhs_n), (rhs, rhs_n)) in a.iter().zip(b) {
+        let ord = lhs.cmp(rhs).then_with(|| compare_int(lhs_n, rhs_n));
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    match (a.get(b.len()), b.get(a.len())) {
+        (Some((suffix, _)), _) if *suffix == SUFFIX_P => Ordering::Greater,
+        (Some(_), _) => Ordering::Less,
+        (_, Some((suffix, _))) if *suffix == SUFFIX_P => Ordering::Less,
+        (_, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{compare, parse, Gentoo};
+
+    #[test]
+    fn parse_valid() {
+        assert_eq!(
+            parse("1.02.3b_rc4_p-r2"),
+            Some(Gentoo {
+                numbers: vec!["1", "02", "3"],
+                letter: Some(b'b'),
+                suffixes: vec![(3, "4"), (4, "")],
+                revision: "2",
+            }),
+        );
+        assert_eq!(parse("1").unwrap().numbers, ["1"]);
+
+        // Invalid versions
+        for version in [
+            "",
+            "a",
+            "1.",
+            "1.a",
+            "1ab",
+            "1_foo",
+            "1-r",
+            "1-1",
+            "1_alpha-r1b",
+        ] {
+            assert!(parse(version).is_none(), "{} must be invalid", version);
+        }
+    }
+
+    #[test]
+    fn compare_ordering() {
+        let versions = [
+            "0.9",
+            "1.0_alpha",
+            "1.0_alpha1",
+            "1.0_alpha2_p1",
+            "1.0_beta",
+            "1.0_pre1",
+            "1.0_rc1",
+            "1.0_rc1-r1",
+            "1.0",
+            "1.0-r1",
+            "1.0_p1",
+            "1.0a",
+            "1.0.1_alpha",
+            "1.01",
+            "1.1",
+            "1.10",
+            "2",
+        ];
+
+        for (i, a) in versions.iter().enumerate() {
+            for (j, b) in versions.iter().enumerate() {
+                assert_eq!(
+                    compare(a, b),
+                    Some(i.cmp(&j).into()),
+                    "Testing that {} is {} {}",
+                    a,
+                    Cmp::from(i.cmp(&j)).sign(),
+                    b,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compare_equal() {
+        assert_eq!(compare("1.0", "1.0-r0"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0_p", "1.0_p0"), Some(Cmp::Eq));
+        assert_eq!(compare("01.0", "1.0"), Some(Cmp::Eq));
+        assert_eq!(compare("1.010", "1.01"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0", "1.0.0"), Some(Cmp::Lt));
+        assert_eq!(compare("1.0", "foo"), None);
+    }
+}
@@ -0,0 +1,291 @@
+//! Maven version scheme.
+//!
+//! Implements the ordering of Maven's
+//! [`ComparableVersion`](https://maven.apache.org/ref/current/maven-artifact/apidocs/org/apache/maven/artifact/versioning/ComparableVersion.html),
+//! which splits versions into nested lists of numbers and qualifiers.
+
+use std::cmp::Ordering;
+
+use crate::Cmp;
+
+/// Well-known qualifiers, in order. The empty qualifier is a release.
+const QUALIFIERS: &[&str] = &["alpha", "beta", "milestone", "rc", "snapshot", "", "sp"];
+
+/// A parsed Maven version item.
+#[derive(Debug, PartialEq, Eq)]
+enum Item {
+    /// A number, as digits with leading zeros stripped.
+    Int(String),
+
+    /// A qualifier.
+    Str(String),
+
+    /// A sub list of items.
+    List(Vec<Item>),
+}
+
+impl Item {
+    /// Create a number or qualifier item from the given token.
+    fn parse(token: &str, digits: bool, followed_by_digit: bool) -> Item {
+        if digits {
+            return Item::Int(token.trim_start_matches('0').into());
+        }
+
+        // Expand single letter shorthands, and resolve aliases
+        let token = match token {
+            "a" if followed_by_digit => "alpha",
+            "b" if followed_by_digit => "beta",
+            "m" if followed_by_digit => "milestone",
+            "ga" | "final" | "release" => "",
+            "cr" => "rc",
+            token => token,
+        };
+        Item::Str(token.into())
+    }
+
+    /// Check whether this item is null, being equal to an absent item.
+    fn is_null(&self) -> bool {
+        match self {
+            Item::Int(n) => n.is_empty(),
+            Item::Str(s) => s.is_empty(),
+            Item::List(l) => l.is_empty(),
+        }
+    }
+}
+
+/// Parse the given version string into a list of items.
+fn parse(version: &str) -> Vec<Item> {
+    let version = version.to_lowercase();
+
+    // Stack of lists, a new sub list is started on a dash and on a digit/letter transition
+    let mut stack: Vec<Vec<Item>> = vec![vec![]];
+    let mut digits = false;
+    let mut start = 0;
+
+    for (i, c) in version.char_indices() {
+        let list = stack.last_mut().unwrap();
+        match c {
+            '.' | '-' => {
+                if i == start {
+                    list.push(Item::Int(String::new()));
+                } else {
+                    list.push(Item::parse(&version[start..i], digits, false));
+                }
+                start = i + 1;
+                if c == '-' {
+                    stack.push(vec![]);
+                }
+            }
+            c if c.is_ascii_digit() => {
+                if !digits && i > start {
+                    list.push(Item::parse(&version[start..i], false, true));
+                    start = i;
+                    stack.push(vec![]);
+                }
+                digits = true;
+            }
+            _ => {
+                if digits && i > start {
+                    list.push(Item::parse(&version[start..i], true, false));
+                    start = i;
+                    stack.push(vec![]);
+                }
+                digits = false;
+            }
+        }
+    }
+    if version.len() > start {
+        let token = &version[start..];
+        stack
+            .last_mut()
+            .unwrap()
+            .push(Item::parse(token, digits, false));
+    }
+
+    // Normalize all lists, and add each sub list to its parent
+    loop {
+        let mut list = stack.pop().unwrap();
+        normalize(&mut list);
+        match stack.last_mut() {
+            Some(parent) => parent.push(Item::List(list)),
+            None => return list,
+        }
+    }
+}
+
+/// Normalize a list by removing trailing null items, skipping over sub lists.
+fn normalize(list: &mut Vec<Item>) {
+    for i in (0..list.len()).rev() {
+        if list[i].is_null() {
+            list.remove(i);
+        } else if !matches!(list[i], Item::List(_)) {
+            break;
+        }
+    }
+}
+
+/// Get a comparable value for the given qualifier.
+///
+/// Well-known qualifiers are ordered by their index, unknown qualifiers after all well-known
+/// qualifiers in lexical order.
+fn comparable_qualifier(qualifier: &str) -> String {
+    match QUALIFIERS.iter().position(|q| q == &qualifier) {
+        Some(i) => i.to_string(),
+        None => format!("{}-{}", QUALIFIERS.len(), qualifier),
+    }
+}
+
+/// Compare an item to an optional other item, an absent item is null.
+fn compare_item(item: &Item, other: Option<&Item>) -> Ordering {
+    match (item, other) {
+        (Item::Int(n), None) => {
+            if n.is_empty() {
+                Ordering::Equal
+            } else {
+                Ordering::Greater
+            }
+        }
+        (Item::Int(a), Some(Item::Int(b))) => a.len().cmp(&b.len()).then_with(|| a.cmp(b)),
+        (Item::Int(_), Some(_)) => Ordering::Greater,
+        (Item::Str(s), None) => comparable_qualifier(s).cmp(&comparable_qualifier("")),
+        (Item::Str(a), Some(Item::Str(b))) => comparable_qualifier(a).cmp(&comparable_qualifier(b)),
+        (Item::Str(_), Some(_)) => Ordering::Less,
+        (Item::List(list), None) => list
+            .first()
+            .map_or(Ordering::Equal, |first| compare_item(first, None)),
+        (Item::List(_), Some(Item::Int(_))) => Ordering::Less,
+        (Item::List(_), Some(Item::Str(_))) => Ordering::Greater,
+        (Item::List(a), Some(Item::List(b))) => compare_lists(a, b),
+    }
+}
+
+/// Compare two lists of items, item by item.
+fn compare_lists(a: &[Item], b: &[Item]) -> Ordering {
+    for i in 0..a.len().max(b.len()) {
+        let ord = match (a.get(i), b.get(i)) {
+            (Some(lhs), rhs) => compare_item(lhs, rhs),
+            (None, Some(rhs)) => compare_item(rhs, None).reverse(),
+            (None, None) => Ordering::Equal,
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Compare two Maven version strings. Any version string is valid.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    Some(compare_lists(&parse(a), &parse(b)).into())
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{compare, parse, Item};
+
+    /// Assert that the given versions are in strictly increasing order.
+    fn assert_ordered(versions: &[&str]) {
+        for (i, a) in versions.iter().enumerate() {
+            for (j, b) in versions.iter().enumerate() {
+                assert_eq!(
+                    compare(a, b),
+                    Some(i.cmp(&j).into()),
+                    "Testing that {} is {} {}",
+                    a,
+                    Cmp::from(i.cmp(&j)).sign(),
+                    b,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parse_items() {
+        assert_eq!(
+            parse("1.0-alpha-1"),
+            [
+                Item::Int("1".into()),
+                Item::List(vec![
+                    Item::Str("alpha".into()),
+                    Item::List(vec![Item::Int("1".into())]),
+                ]),
+            ],
+        );
+        assert_eq!(parse("1.0.0-ga"), [Item::Int("1".into())]);
+    }
+
+    #[test]
+    fn compare_qualifiers() {
+        // Test cases from the Maven ComparableVersion test suite
+        assert_ordered(&[
+            "1-alpha2snapshot",
+            "1-alpha2",
+            "1-alpha-123",
+            "1-beta-2",
+            "1-beta123",
+            "1-m2",
+            "1-m11",
+            "1-rc",
+            "1-cr2",
+            "1-rc123",
+            "1-SNAPSHOT",
+            "1",
+            "1-sp",
+            "1-sp2",
+            "1-sp123",
+            "1-abc",
+            "1-def",
+            "1-pom-1",
+            "1-1-snapshot",
+            "1-1",
+            "1-2",
+            "1-123",
+        ]);
+    }
+
+    #[test]
+    fn compare_numbers() {
+        // Test cases from the Maven ComparableVersion test suite
+        assert_ordered(&[
+            "2.0", "2-1", "2.0.a", "2.0.0.a", "2.0.2", "2.0.123", "2.1.0", "2.1-a", "2.1b",
+            "2.1-c", "2.1-1", "2.1.0.1", "2.2", "2.123", "11.a2", "11.a11", "11.b2", "11.b11",
+            "11.m2", "11.m11", "11", "11.a", "11b", "11c", "11m",
+        ]);
+    }
+
+    #[test]
+    fn compare_equal() {
+        // Test cases from the Maven ComparableVersion test suite
+        let cases = [
+            ("1", "1.0"),
+            ("1", "1.0.0"),
+            ("1.0", "1.0.0"),
+            ("1", "1-0"),
+            ("1", "1.0-0"),
+            ("1.0", "1.0-0"),
+            ("1a", "1-a"),
+            ("1a", "1.0-a"),
+            ("1a", "1.0.0-a"),
+            ("1.0a", "1-a"),
+            ("1.0.0a", "1-a"),
+            ("1x", "1-x"),
+            ("1x", "1.0-x"),
+            ("1ga", "1"),
+            ("1release", "1"),
+            ("1final", "1"),
+            ("1cr", "1rc"),
+            ("1a1", "1-alpha-1"),
+            ("1b2", "1-beta-2"),
+            ("1m3", "1-milestone-3"),
+            ("1X", "1x"),
+            ("1A", "1a"),
+            ("1-SNAPSHOT", "1-snapshot"),
+        ];
+        for (a, b) in cases {
+            assert_eq!(compare(a, b), Some(Cmp::Eq), "Testing that {} == {}", a, b);
+        }
+    }
+}
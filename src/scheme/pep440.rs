@@ -0,0 +1,317 @@
+//! Python PEP 440 version scheme.
+//!
+//! Implements parsing and ordering as specified in
+//! [PEP 440](https://peps.python.org/pep-0440/), including its normalization rules.
+
+use std::cmp::Ordering;
+
+use crate::Cmp;
+
+/// Phase of a pre-release, in order.
+const PRE_PHASES: &[(&str, u8)] = &[
+    ("alpha", 0),
+    ("beta", 1),
+    ("preview", 2),
+    ("pre", 2),
+    ("rc", 2),
+    ("a", 0),
+    ("b", 1),
+    ("c", 2),
+];
+
+/// A parsed PEP 440 version.
+#[derive(Debug, PartialEq, Eq)]
+struct Pep440 {
+    epoch: u64,
+    release: Vec<u64>,
+    pre: Option<(u8, u64)>,
+    post: Option<u64>,
+    dev: Option<u64>,
+    local: Vec<String>,
+}
+
+/// Parse the given version string as PEP 440 version.
+fn parse(version: &str) -> Option<Pep440> {
+    let version = version.trim().to_lowercase();
+    let version = version.strip_prefix('v').unwrap_or(&version);
+
+    // Split the local version label
+    let (version, local) = match version.split_once('+') {
+        Some((version, local)) => (version, parse_local(local)?),
+        None => (version, vec![]),
+    };
+
+    // Take the epoch
+    let (epoch, mut rest) = match version.split_once('!') {
+        Some((epoch, rest)) => (parse_number(epoch)?, rest),
+        None => (0, version),
+    };
+
+    // Take the release segment
+    let mut release = vec![];
+    loop {
+        let (n, tail) = take_number(rest)?;
+        release.push(n);
+        rest = tail;
+        match rest.strip_prefix('.') {
+            Some(tail) if tail.starts_with(|c: char| c.is_ascii_digit()) => rest = tail,
+            _ => break,
+        }
+    }
+
+    // Take the pre-release segment
+    let pre = take_words(rest, PRE_PHASES).map(|(phase, tail)| {
+        let (n, tail) = take_implicit_number(tail);
+        rest = tail;
+        (phase, n)
+    });
+
+    // Take the post-release segment, possibly implicit like `1.0-1`
+    let post = match rest.strip_prefix('-').and_then(take_number) {
+        Some((n, tail)) => {
+            rest = tail;
+            Some(n)
+        }
+        None => take_words(rest, &[("post", 0), ("rev", 0), ("r", 0)]).map(|(_, tail)| {
+            let (n, tail) = take_implicit_number(tail);
+            rest = tail;
+            n
+        }),
+    };
+
+    // Take the development release segment
+    let dev = take_words(rest, &[("dev", 0)]).map(|(_, tail)| {
+        let (n, tail) = take_implicit_number(tail);
+        rest = tail;
+        n
+    });
+
+    // Nothing may be left
+    if !rest.is_empty() {
+        return None;
+    }
+
+    Some(Pep440 {
+        epoch,
+        release,
+        pre,
+        post,
+        dev,
+        local,
+    })
+}
+
+/// Parse a local version label into its segments.
+fn parse_local(local: &str) -> Option<Vec<String>> {
+    local
+        .split(|c| matches!(c, '.' | '-' | '_'))
+        .map(|segment| {
+            if !segment.is_empty() && segment.chars().all(|c| c.is_ascii_alphanumeric()) {
+                Some(segment.to_string())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Parse a string consisting of just digits as number.
+fn parse_number(number: &str) -> Option<u64> {
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+/// Take a leading number from the given string, returning the number and the remainder.
+fn take_number(s: &str) -> Option<(u64, &str)> {
+    let at = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    Some((parse_number(&s[..at])?, &s[at..]))
+}
+
+/// Take an optional number prefixed by an optional separator, defaulting to zero.
+fn take_implicit_number(s: &str) -> (u64, &str) {
+    take_number(strip_separator(s)).unwrap_or((0, s))
+}
+
+/// Take one of the given words prefixed by an optional separator, returning its value and the
+/// remainder.
+fn take_words<'a>(s: &'a str, words: &[(&str, u8)]) -> Option<(u8, &'a str)> {
+    let s = strip_separator(s);
+    words
+        .iter()
+        .find(|(word, _)| s.starts_with(word))
+        .map(|(word, value)| (*value, &s[word.len()..]))
+}
+
+/// Strip a single leading separator from the given string.
+fn strip_separator(s: &str) -> &str {
+    s.strip_prefix(|c| matches!(c, '.' | '-' | '_'))
+        .unwrap_or(s)
+}
+
+/// Check whether the given version string is a valid PEP 440 version.
+pub(crate) fn is_valid(version: &str) -> bool {
+    parse(version).is_some()
+}
+
+/// Get the epoch of the given PEP 440 version string, `0` if not specified.
+///
+/// `None` is returned if the version string is not a valid PEP 440 version.
+pub(crate) fn epoch(version: &str) -> Option<i64> {
+    Some(i64::try_from(parse(version)?.epoch).unwrap_or(i64::MAX))
+}
+
+/// Compare two PEP 440 version strings.
+///
+/// `None` is returned if either version string is not a valid PEP 440 version.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    Some(compare_parsed(&parse(a)?, &parse(b)?).into())
+}
+
+/// Compare two parsed PEP 440 versions.
+fn compare_parsed(a: &Pep440, b: &Pep440) -> Ordering {
+    a.epoch
+        .cmp(&b.epoch)
+        .then_with(|| compare_release(&a.release, &b.release))
+        .then_with(|| pre_key(a).cmp(&pre_key(b)))
+        .then_with(|| a.post.cmp(&b.post))
+        .then_with(|| dev_key(a).cmp(&dev_key(b)))
+        .then_with(|| compare_local(&a.local, &b.local))
+}
+
+/// Compare release segments, padding the shortest with zeros.
+fn compare_release(a: &[u64], b: &[u64]) -> Ordering {
+    let len = a.len().max(b.len());
+    (0..len)
+        .map(|i| {
+            let lhs = a.get(i).unwrap_or(&0);
+            let rhs = b.get(i).unwrap_or(&0);
+            lhs.cmp(rhs)
+        })
+        .find(|ord| ord != &Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Sort key for the pre-release segment.
+///
+/// A development release without pre or post segment sorts before any pre-release, a version
+/// without pre-release segment sorts after any pre-release.
+fn pre_key(version: &Pep440) -> (u8, u8, u64) {
+    match (version.pre, version.post, version.dev) {
+        (None, None, Some(_)) => (0, 0, 0),
+        (Some((phase, n)), _, _) => (1, phase, n),
+        (None, _, _) => (2, 0, 0),
+    }
+}
+
+/// Sort key for the development release segment, which sorts before no segment.
+fn dev_key(version: &Pep440) -> (u8, u64) {
+    match version.dev {
+        Some(n) => (0, n),
+        None => (1, 0),
+    }
+}
+
+/// Compare local version labels.
+///
+/// Numeric segments sort after alphanumeric segments, and are compared numerically.
+fn compare_local(a: &[String], b: &[String]) -> Ordering {
+    for (lhs, rhs) in a.iter().zip(b) {
+        let ord = match (lhs.parse::<u64>(), rhs.parse::<u64>()) {
+            (Ok(lhs), Ok(rhs)) => lhs.cmp(&rhs),
+            (Ok(_), Err(_)) => Ordering::Greater,
+            (Err(_), Ok(_)) => Ordering::Less,
+            (Err(_), Err(_)) => lhs.cmp(rhs),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{compare, parse, Pep440};
+
+    #[test]
+    fn parse_normalized() {
+        assert_eq!(
+            parse(" V1!2.3-RC.4.post5-dev6+Ubuntu-1 "),
+            Some(Pep440 {
+                epoch: 1,
+                release: vec![2, 3],
+                pre: Some((2, 4)),
+                post: Some(5),
+                dev: Some(6),
+                local: vec!["ubuntu".into(), "1".into()],
+            }),
+        );
+        assert_eq!(parse("1.0-1").unwrap().post, Some(1));
+        assert_eq!(parse("1.0rev").unwrap().post, Some(0));
+        assert_eq!(parse("1.0c1").unwrap().pre, Some((2, 1)));
+        assert_eq!(parse("1.0alpha").unwrap().pre, Some((0, 0)));
+
+        // Invalid versions
+        assert!(parse("").is_none());
+        assert!(parse("abc").is_none());
+        assert!(parse("1.0 final").is_none());
+        assert!(parse("1.0+").is_none());
+        assert!(parse("1.0+abc..1").is_none());
+        assert!(parse("a!1.0").is_none());
+    }
+
+    #[test]
+    fn compare_ordering() {
+        // Ordering example from the PEP 440 specification
+        let versions = [
+            "1.0.dev456",
+            "1.0a1",
+            "1.0a2.dev456",
+            "1.0a12.dev456",
+            "1.0a12",
+            "1.0b1.dev456",
+            "1.0b2",
+            "1.0b2.post345.dev456",
+            "1.0b2.post345",
+            "1.0rc1.dev456",
+            "1.0rc1",
+            "1.0",
+            "1.0+abc.5",
+            "1.0+abc.7",
+            "1.0+5",
+            "1.0.post456.dev34",
+            "1.0.post456",
+            "1.0.15",
+            "1.1.dev1",
+            "1!0.1",
+        ];
+
+        for (i, a) in versions.iter().enumerate() {
+            for (j, b) in versions.iter().enumerate() {
+                assert_eq!(
+                    compare(a, b),
+                    Some(i.cmp(&j).into()),
+                    "Testing that {} is {} {}",
+                    a,
+                    Cmp::from(i.cmp(&j)).sign(),
+                    b,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compare_equal() {
+        assert_eq!(compare("1.0", "1.0.0"), Some(Cmp::Eq));
+        assert_eq!(compare("v1.0", "1.0"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0-1", "1.0.post1"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0alpha1", "1.0a1"), Some(Cmp::Eq));
+        assert_eq!(compare("0!1.0", "1.0"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0", "abc"), None);
+    }
+}
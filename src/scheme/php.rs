@@ -0,0 +1,295 @@
+//! PHP version scheme.
+//!
+//! Implements the exact algorithm of PHP's
+//! [`version_compare`](https://www.php.net/manual/en/function.version-compare.php), ordering
+//! special forms as `dev < alpha = a < beta = b < RC = rc < # < pl = p`.
+
+use std::cmp::Ordering;
+
+use crate::Cmp;
+
+/// The special version forms with their order, matched by prefix in this order.
+///
+/// A number is compared as the `#` form.
+const SPECIAL_FORMS: [(&[u8], i32); 10] = [
+    (b"dev", 0),
+    (b"alpha", 1),
+    (b"a", 1),
+    (b"beta", 2),
+    (b"b", 2),
+    (b"RC", 3),
+    (b"rc", 3),
+    (b"#", 4),
+    (b"pl", 5),
+    (b"p", 5),
+];
+
+/// The form a number is compared as, against a special form.
+const NUMBER_FORM: &[u8] = b"#N#";
+
+/// Canonicalize the given version string.
+///
+/// Replaces `-`, `_` and `+` with a `.`, and inserts a `.` between each run of digits and
+/// non-digits. Other non-alphanumeric characters are replaced with a `.` as well, except directly
+/// after a digit where PHP keeps them as their own segment. Repeated dots are collapsed, the first
+/// character is always kept as is.
+fn canonicalize(version: &[u8]) -> Vec<u8> {
+    let is_digit = |c: u8| c.is_ascii_digit();
+    let is_non_digit = |c: u8| !c.is_ascii_digit() && c != b'.';
+
+    let mut canonical = Vec::with_capacity(version.len() * 2);
+    let (mut last, rest) = match version.split_first() {
+        Some((&first, rest)) => (first, rest),
+        None => return canonical,
+    };
+    canonical.push(last);
+
+    for &c in rest {
+        let separated = canonical.last() == Some(&b'.');
+        if matches!(c, b'-' | b'_' | b'+') {
+            if !separated {
+                canonical.push(b'.');
+            }
+        } else if (is_non_digit(last) && is_digit(c)) || (is_digit(last) && is_non_digit(c)) {
+            if !separated {
+                canonical.push(b'.');
+            }
+            canonical.push(c);
+        } else if !c.is_ascii_alphanumeric() {
+            if !separated {
+                canonical.push(b'.');
+            }
+        } else {
+            canonical.push(c);
+        }
+        last = c;
+    }
+    canonical
+}
+
+/// Get the order of the given special form, `-1` if it isn't special.
+fn special_order(form: &[u8]) -> i32 {
+    SPECIAL_FORMS
+        .iter()
+        .find(|(name, _)| form.starts_with(name))
+        .map_or(-1, |(_, order)| *order)
+}
+
+/// Compare two special version forms.
+fn compare_special(a: &[u8], b: &[u8]) -> Ordering {
+    special_order(a).cmp(&special_order(b))
+}
+
+/// Parse a leading number like C's `strtol`, saturating on overflow.
+fn parse_long(digits: &[u8]) -> i64 {
+    digits
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .fold(0i64, |n, c| {
+            n.saturating_mul(10).saturating_add(i64::from(c - b'0'))
+        })
+}
+
+/// Check whether the given position in a version string holds a digit.
+fn is_digit_at(version: &[u8], i: usize) -> bool {
+    version.get(i).map_or(false, u8::is_ascii_digit)
+}
+
+/// Compare two version strings, following PHP's `php_version_compare` step by step.
+fn version_compare(a: &[u8], b: &[u8]) -> Ordering {
+    if a.is_empty() || b.is_empty() {
+        return (!a.is_empty()).cmp(&!b.is_empty());
+    }
+
+    // Version strings starting with a `#` aren't canonicalized
+    let canonical = |v: &[u8]| {
+        if v[0] == b'#' {
+            v.to_vec()
+        } else {
+            canonicalize(v)
+        }
+    };
+    let (a, b) = (canonical(a), canonical(b));
+
+    // Compare segments while both versions have a next segment
+    let find_dot = |v: &[u8], from: usize| v[from..].iter().position(|&c| c == b'.');
+    let (mut p1, mut p2) = (0, 0);
+    let (mut n1, mut n2) = (Some(0), Some(0));
+    let mut compare = Ordering::Equal;
+    while p1 < a.len() && p2 < b.len() && n1.is_some() && n2.is_some() {
+        n1 = find_dot(&a, p1).map(|i| p1 + i);
+        n2 = find_dot(&b, p2).map(|i| p2 + i);
+        let seg1 = &a[p1..n1.unwrap_or(a.len())];
+        let seg2 = &b[p2..n2.unwrap_or(b.len())];
+
+        compare = match (is_digit_at(seg1, 0), is_digit_at(seg2, 0)) {
+            (true, true) => parse_long(seg1).cmp(&parse_long(seg2)),
+            (false, false) => compare_special(seg1, seg2),
+            (true, false) => compare_special(NUMBER_FORM, seg2),
+            (false, true) => compare_special(seg1, NUMBER_FORM),
+        };
+        if compare != Ordering::Equal {
+            break;
+        }
+
+        if let Some(n1) = n1 {
+            p1 = n1 + 1;
+        }
+        if let Some(n2) = n2 {
+            p2 = n2 + 1;
+        }
+    }
+
+    // Compare the remainder of the longer version
+    if compare == Ordering::Equal {
+        if n1.is_some() {
+            compare = if is_digit_at(&a, p1) {
+                Ordering::Greater
+            } else {
+                version_compare(a.get(p1..).unwrap_or_default(), NUMBER_FORM)
+            };
+        } else if n2.is_some() {
+            compare = if is_digit_at(&b, p2) {
+                Ordering::Less
+            } else {
+                version_compare(NUMBER_FORM, b.get(p2..).unwrap_or_default())
+            };
+        }
+    }
+    compare
+}
+
+/// Compare two version strings like PHP's `version_compare`. Any version string is valid.
+///
+/// Like in PHP, version strings end at the first NUL character.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    let c_str = |v: &str| v.split('\0').next().unwrap_or_default().as_bytes().to_vec();
+    Some(version_compare(&c_str(a), &c_str(b)).into())
+}
+
+/// Compare two version strings like `compare`, consistently both ways.
+///
+/// PHP orders a version ending with a separator below any version sharing its prefix, even
+/// itself. If comparing both ways disagrees, the version strings are ordered as strings instead.
+pub(crate) fn compare_consistent(a: &str, b: &str) -> Option<Cmp> {
+    let cmp = compare(a, b)?;
+    if compare(b, a)? == cmp.flip() {
+        Some(cmp)
+    } else {
+        Some(a.cmp(b).into())
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{canonicalize, compare, compare_consistent};
+
+    #[test]
+    fn canonical() {
+        for (version, canonical) in [
+            ("1.0.0", "1.0.0"),
+            ("1.0rc1", "1.0.rc.1"),
+            ("1.0-dev", "1.0.dev"),
+            ("1.0_beta+2", "1.0.beta.2"),
+            ("1..0  1", "1.0. .1"),
+            ("1..0-_1", "1.0.1"),
+            ("-1", "-.1"),
+            ("1.0#1", "1.0.#.1"),
+            ("1.a#1", "1.a.1"),
+            ("", ""),
+        ] {
+            assert_eq!(canonicalize(version.as_bytes()), canonical.as_bytes());
+        }
+    }
+
+    /// Ported from PHP's `ext/standard/tests/versioning/version_compare.phpt`.
+    #[test]
+    fn php_tests() {
+        assert_eq!(compare("1", "2"), Some(Cmp::Lt));
+        assert_eq!(compare("10", "2"), Some(Cmp::Gt));
+        assert_eq!(compare("1.0", "1.1"), Some(Cmp::Lt));
+        assert_eq!(compare("1.2", "1.0.1"), Some(Cmp::Gt));
+
+        // Special forms, in order with their rank
+        let forms = [
+            ("-dev", 0),
+            ("a1", 1),
+            ("b1", 2),
+            ("RC1", 3),
+            ("rc1", 3),
+            ("", 4),
+            ("pl1", 5),
+        ];
+        for (f1, r1) in forms {
+            for (f2, r2) in forms {
+                let (a, b) = (format!("1.0{}", f1), format!("1.0{}", f2));
+                assert_eq!(
+                    compare(&a, &b),
+                    Some(r1.cmp(&r2).into()),
+                    "Testing that {} is {} {}",
+                    a,
+                    Cmp::from(r1.cmp(&r2)).sign(),
+                    b,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compare_quirks() {
+        for (a, b, cmp) in [
+            // Documented examples
+            ("5.2", "5.2.0", Cmp::Lt),
+            ("5.3.0-dev", "5.3.0", Cmp::Lt),
+            ("5.3.0", "5.3.0-dev", Cmp::Gt),
+            // Longer versions are greater, unless followed by a special form below a number
+            ("1.0.0", "1.0", Cmp::Gt),
+            ("1.0", "1.0.0", Cmp::Lt),
+            ("1.0.0", "1.0.0.0", Cmp::Lt),
+            ("1.0", "1.0.rc1", Cmp::Gt),
+            ("1.0", "1.0.pl1", Cmp::Lt),
+            // Special forms match by prefix, other text is below everything
+            ("1.0alpha", "1.0a", Cmp::Eq),
+            ("1.0beta2", "1.0b1", Cmp::Gt),
+            ("1.0patch", "1.0p", Cmp::Eq),
+            ("1.0foo", "1.0dev", Cmp::Lt),
+            ("1.0RC1", "1.0rc1", Cmp::Eq),
+            ("1.0Rc1", "1.0rc1", Cmp::Lt),
+            // Separators are all equal
+            ("1-0_0+0", "1.0.0.0", Cmp::Eq),
+            ("1.0.0-1", "1.0.0.1", Cmp::Eq),
+            // Empty versions and trailing separators
+            ("", "", Cmp::Eq),
+            ("", "0", Cmp::Lt),
+            ("0", "", Cmp::Gt),
+            ("1.", "1", Cmp::Lt),
+            // A trailing separator is less than anything sharing the prefix, even itself
+            ("1.0.", "1.0.", Cmp::Lt),
+            ("1.0.é", "1.0.rc", Cmp::Lt),
+            ("1.0.rc", "1.0.é", Cmp::Lt),
+            // Numbers are compared numerically, saturating
+            ("1.010", "1.9", Cmp::Gt),
+            ("99999999999999999999", "99999999999999999998", Cmp::Eq),
+            // Strings end at a NUL character
+            ("1.0\0.1", "1.0", Cmp::Eq),
+        ] {
+            assert_eq!(compare(a, b), Some(cmp), "{:?} vs {:?}", a, b);
+        }
+    }
+    #[test]
+    fn compare_consistent_both_ways() {
+        for (a, b, cmp) in [
+            ("1.0", "1.0.0", Cmp::Lt),
+            ("1.0rc1", "1.0.RC.1", Cmp::Eq),
+            ("1.", "1", Cmp::Lt),
+            ("1.0.", "1.0.", Cmp::Eq),
+            ("1.0.rc", "1.0.é", Cmp::Lt),
+            ("1.0.é", "1.0.rc", Cmp::Gt),
+        ] {
+            assert_eq!(compare_consistent(a, b), Some(cmp), "{:?} vs {:?}", a, b);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Rust toolchain version scheme.
+//!
+//! Implements the ordering of Rust toolchain versions like `1.75.0`, `1.75.0-beta.3` and
+//! `1.75.0-nightly (abc123 2024-01-01)`, as printed by `rustc --version`.
+
+use std::cmp::Ordering;
+
+use crate::Cmp;
+
+/// A release channel, ordered as `nightly < beta < stable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Channel {
+    /// Nightly, ordered by the commit date in `Rustc::date`.
+    Nightly,
+
+    /// Beta, ordered by the beta number in `Rustc::beta`.
+    Beta,
+
+    /// Stable.
+    Stable,
+}
+
+/// A parsed Rust toolchain version.
+#[derive(Debug, PartialEq, Eq)]
+struct Rustc<'a> {
+    /// The major, minor and patch number.
+    release: [u64; 3],
+
+    /// The release channel.
+    channel: Channel,
+
+    /// The beta number, if this is a numbered beta.
+    beta: Option<u64>,
+
+    /// The commit date as `YYYY-MM-DD`, if this is a nightly with a date.
+    date: Option<&'a str>,
+}
+
+/// Parse the given version string as Rust toolchain version.
+///
+/// An optional `rustc` prefix and a commit hash and date suffix in parentheses are supported.
+fn parse(version: &str) -> Option<Rustc<'_>> {
+    let version = version.trim();
+    let version = version.strip_prefix("rustc ").unwrap_or(version);
+
+    // Split the commit suffix, and take its date
+    let (version, date) = match version.split_once(' ') {
+        Some((version, commit)) => {
+            let commit = commit.trim().strip_prefix('(')?.strip_suffix(')')?;
+            let date = commit.split_whitespace().last().filter(|d| is_date(d));
+            (version, date)
+        }
+        None => (version, None),
+    };
+
+    // Split the channel
+    let (release, channel, beta) = match version.split_once('-') {
+        Some((release, "nightly")) => (release, Channel::Nightly, None),
+        Some((release, "beta")) => (release, Channel::Beta, None),
+        Some((release, beta)) => {
+            let number = beta.strip_prefix("beta.")?;
+            if !is_numeric(number) {
+                return None;
+            }
+            (release, Channel::Beta, Some(number.parse().ok()?))
+        }
+        None => (version, Channel::Stable, None),
+    };
+
+    // Take exactly three release numbers
+    let mut numbers = release.split('.');
+    let mut release = [0; 3];
+    for number in release.iter_mut() {
+        let text = numbers.next().filter(|n| is_numeric(n))?;
+        *number = text.parse().ok()?;
+    }
+    if numbers.next().is_some() {
+        return None;
+    }
+
+    // The date only identifies nightlies
+    let date = date.filter(|_| channel == Channel::Nightly);
+
+    Some(Rustc {
+        release,
+        channel,
+        beta,
+        date,
+    })
+}
+
+/// Check whether the given string is a non-empty string of digits.
+fn is_numeric(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|c| c.is_ascii_digit())
+}
+
+/// Check whether the given string is a `YYYY-MM-DD` date.
+fn is_date(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, c)| match i {
+            4 | 7 => *c == b'-',
+            _ => c.is_ascii_digit(),
+        })
+}
+
+/// Check whether the given version string is a valid Rust toolchain version.
+pub(crate) fn is_valid(version: &str) -> bool {
+    parse(version).is_some()
+}
+
+/// Compare two Rust toolchain version strings.
+///
+/// Release numbers are compared first, then the channel. Betas are ordered by their number, a
+/// beta without number is less than any numbered one. Nightlies are ordered by their commit date,
+/// a nightly without date is less than any dated one.
+///
+/// `None` is returned if either version string is invalid.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    Some(compare_parsed(&parse(a)?, &parse(b)?).into())
+}
+
+/// Compare two parsed Rust toolchain versions.
+fn compare_parsed(a: &Rustc, b: &Rustc) -> Ordering {
+    a.release
+        .cmp(&b.release)
+        .then(a.channel.cmp(&b.channel))
+        .then(a.beta.cmp(&b.beta))
+        .then(a.date.cmp(&b.date))
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{compare, parse, Channel, Rustc};
+
+    #[test]
+    fn parse_valid() {
+        assert_eq!(
+            parse("1.75.0-nightly (abc123 2024-01-01)"),
+            Some(Rustc {
+                release: [1, 75, 0],
+                channel: Channel::Nightly,
+                beta: None,
+                date: Some("2024-01-01"),
+            }),
+        );
+        assert_eq!(
+            parse("rustc 1.75.0-beta.3 (abc123 2023-12-01)"),
+            Some(Rustc {
+                release: [1, 75, 0],
+                channel: Channel::Beta,
+                beta: Some(3),
+                date: None,
+            }),
+        );
+        assert_eq!(parse("1.75.0").unwrap().channel, Channel::Stable);
+        assert_eq!(parse("1.75.0-nightly (abc123)").unwrap().date, None);
+
+        // Invalid versions
+        for version in [
+            "",
+            "1.75",
+            "1.75.0.1",
+            "1.75.x",
+            "v1.75.0",
+            "1.75.0-dev",
+            "1.75.0-beta.",
+            "1.75.0-beta.x",
+            "1.75.0-beta3",
+            "1.75.0-nightly abc",
+            "1.75.0-nightly (abc",
+        ] {
+            assert!(parse(version).is_none(), "{} must be invalid", version);
+        }
+    }
+
+    #[test]
+    fn compare_ordering() {
+        let versions = [
+            "1.74.1",
+            "1.75.0-nightly",
+            "1.75.0-nightly (abc123 2023-11-01)",
+            "1.75.0-nightly (def456 2023-11-02)",
+            "1.75.0-beta",
+            "1.75.0-beta.1",
+            "1.75.0-beta.3",
+            "1.75.0-beta.10",
+            "1.75.0",
+            "1.75.1-nightly",
+            "1.76.0",
+        ];
+
+        for (i, a) in versions.iter().enumerate() {
+            for (j, b) in versions.iter().enumerate() {
+                assert_eq!(
+                    compare(a, b),
+                    Some(i.cmp(&j).into()),
+                    "Testing that {} is {} {}",
+                    a,
+                    Cmp::from(i.cmp(&j)).sign(),
+                    b,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compare_equal() {
+        assert_eq!(
+            compare("rustc 1.75.0 (82e1608df 2023-12-21)", "1.75.0"),
+            Some(Cmp::Eq)
+        );
+        assert_eq!(
+            compare("1.75.0-beta.3 (abc 2023-12-01)", "1.75.0-beta.3"),
+            Some(Cmp::Eq)
+        );
+        assert_eq!(compare("1.75.0", "1.75"), None);
+    }
+}
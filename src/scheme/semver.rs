@@ -0,0 +1,206 @@
+//! Semantic versioning scheme.
+//!
+//! Implements strict parsing and precedence as specified in
+//! [Semantic Versioning 2.0.0](https://semver.org/), for versions like `1.2.3-rc.1+build.5`.
+
+use std::cmp::Ordering;
+
+use crate::Cmp;
+
+/// A parsed semantic version. Build metadata is validated but not kept, as it has no precedence.
+#[derive(Debug, PartialEq, Eq)]
+struct Semver<'a> {
+    core: [&'a str; 3],
+    pre: Vec<&'a str>,
+}
+
+/// Parse the given version string as semantic version.
+fn parse(version: &str) -> Option<Semver<'_>> {
+    // Split and validate the build metadata
+    let version = match version.split_once('+') {
+        Some((version, build)) => {
+            if !build.split('.').all(is_identifier) {
+                return None;
+            }
+            version
+        }
+        None => version,
+    };
+
+    // Split the pre-release identifiers
+    let (core, pre) = match version.split_once('-') {
+        Some((core, pre)) => {
+            let pre: Vec<&str> = pre.split('.').collect();
+            if !pre
+                .iter()
+                .all(|id| is_identifier(id) && !is_leading_zero(id))
+            {
+                return None;
+            }
+            (core, pre)
+        }
+        None => (version, vec![]),
+    };
+
+    // Take exactly three numeric core components
+    let mut numbers = core.split('.');
+    let mut core = [""; 3];
+    for number in core.iter_mut() {
+        *number = numbers.next()?;
+        if !is_numeric(number) || is_leading_zero(number) {
+            return None;
+        }
+    }
+    if numbers.next().is_some() {
+        return None;
+    }
+
+    Some(Semver { core, pre })
+}
+
+/// Check whether the given string is a valid identifier, being non-empty alphanumerics and dashes.
+fn is_identifier(id: &str) -> bool {
+    !id.is_empty() && id.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'-')
+}
+
+/// Check whether the given string is a non-empty string of digits.
+fn is_numeric(id: &str) -> bool {
+    !id.is_empty() && id.bytes().all(|c| c.is_ascii_digit())
+}
+
+/// Check whether the given string is numeric with a disallowed leading zero.
+fn is_leading_zero(id: &str) -> bool {
+    is_numeric(id) && id.len() > 1 && id.starts_with('0')
+}
+
+/// Compare two strings of digits as integers.
+fn compare_int(a: &str, b: &str) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Check whether the given version string is a valid semantic version.
+pub(crate) fn is_valid(version: &str) -> bool {
+    parse(version).is_some()
+}
+
+/// Compare two semantic version strings.
+///
+/// `None` is returned if either version string is not a valid semantic version.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    Some(compare_parsed(&parse(a)?, &parse(b)?).into())
+}
+
+/// Compare two parsed semantic versions.
+fn compare_parsed(a: &Semver, b: &Semver) -> Ordering {
+    a.core
+        .iter()
+        .zip(&b.core)
+        .map(|(lhs, rhs)| compare_int(lhs, rhs))
+        .find(|ord| ord != &Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| compare_pre(&a.pre, &b.pre))
+}
+
+/// Compare pre-release identifiers.
+///
+/// A version without pre-release is greater. Numeric identifiers are compared as integers and
+/// are less than alphanumeric identifiers. If all shared identifiers are equal, the version with
+/// more identifiers is greater.
+fn compare_pre(a: &[&str], b: &[&str]) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    for (lhs, rhs) in a.iter().zip(b) {
+        let ord = match (is_numeric(lhs), is_numeric(rhs)) {
+            (true, true) => compare_int(lhs, rhs),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => lhs.cmp(rhs),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{compare, parse, Semver};
+
+    #[test]
+    fn parse_valid() {
+        assert_eq!(
+            parse("1.20.3-rc.1+build.5"),
+            Some(Semver {
+                core: ["1", "20", "3"],
+                pre: vec!["rc", "1"],
+            }),
+        );
+        assert_eq!(parse("0.0.0-0a.x-y").unwrap().pre, ["0a", "x-y"]);
+
+        // Invalid versions
+        for version in [
+            "",
+            "1",
+            "1.2",
+            "1.2.3.4",
+            "01.2.3",
+            "1.2.3-",
+            "1.2.3-01",
+            "1.2.3-a..b",
+            "1.2.3+",
+            "1.2.3+a_b",
+            "v1.2.3",
+            "1.2.a",
+        ] {
+            assert!(parse(version).is_none(), "{} must be invalid", version);
+        }
+    }
+
+    #[test]
+    fn compare_ordering() {
+        // Precedence example from the specification
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.1.0",
+            "1.10.0",
+            "2.0.0",
+        ];
+
+        for (i, a) in versions.iter().enumerate() {
+            for (j, b) in versions.iter().enumerate() {
+                assert_eq!(
+                    compare(a, b),
+                    Some(i.cmp(&j).into()),
+                    "Testing that {} is {} {}",
+                    a,
+                    Cmp::from(i.cmp(&j)).sign(),
+                    b,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compare_equal() {
+        assert_eq!(compare("1.0.0+build.1", "1.0.0+build.2"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0.0-rc.1+build", "1.0.0-rc.1"), Some(Cmp::Eq));
+        assert_eq!(compare("1.0.0", "1.0"), None);
+    }
+}
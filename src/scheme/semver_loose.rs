@@ -0,0 +1,125 @@
+//! Loose semantic versioning scheme.
+//!
+//! Implements semver-like precedence for versions with any number of release parts, where a dash
+//! introduces a pre-release block, such as `1.2-rc.1` or `1.2.3.4-3+build`.
+
+use std::cmp::Ordering;
+
+use crate::{Cmp, Version};
+
+/// Split the given version string into its release and optional pre-release block.
+///
+/// Build metadata after a `+` is dropped, as it has no precedence. The pre-release block starts at
+/// the first `-` in the version.
+fn parse(version: &str) -> (&str, Option<&str>) {
+    let version = version
+        .split_once('+')
+        .map_or(version, |(version, _)| version);
+    match version.split_once('-') {
+        Some((release, pre)) => (release, Some(pre)),
+        None => (version, None),
+    }
+}
+
+/// Get a generic version string to compare the given pre-release block with.
+///
+/// A generic version must have a number part, so a leading zero part is added to support
+/// pre-release blocks with just text such as `alpha`. It is equal for all blocks.
+fn pre_version(pre: &str) -> String {
+    format!("0.{}", pre)
+}
+
+/// Check whether the given version string is a valid loose semantic version.
+///
+/// The release must be a valid generic version, and a dash must be followed by a non-empty
+/// pre-release block.
+pub(crate) fn is_valid(version: &str) -> bool {
+    let (release, pre) = parse(version);
+    Version::from(release).is_some()
+        && pre.map_or(true, |pre| {
+            !pre.is_empty() && Version::from(&pre_version(pre)).is_some()
+        })
+}
+
+/// Compare two loose semantic version strings.
+///
+/// The release parts are compared generically first. A version without pre-release block is
+/// greater, two pre-release blocks are compared generically. Unlike strict semantic versioning,
+/// text is therefore less than numbers, so `1.2-rc < 1.2-1`.
+///
+/// `None` is returned if either version string is invalid.
+pub(crate) fn compare(a: &str, b: &str) -> Option<Cmp> {
+    if !is_valid(a) || !is_valid(b) {
+        return None;
+    }
+    let (a, a_pre) = parse(a);
+    let (b, b_pre) = parse(b);
+
+    let ord = crate::cmp(a, b).ok()?.then(match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => crate::cmp(pre_version(a), pre_version(b)).ok()?,
+    });
+    Some(ord.into())
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Cmp;
+
+    use super::{compare, is_valid, parse};
+
+    #[test]
+    fn parse_blocks() {
+        assert_eq!(parse("1.2.3"), ("1.2.3", None));
+        assert_eq!(parse("1.2-3.4_5"), ("1.2", Some("3.4_5")));
+        assert_eq!(parse("1.2-rc-1+build-5"), ("1.2", Some("rc-1")));
+
+        assert!(is_valid("1.2-rc.1+build"));
+        assert!(is_valid("1.2-3.4_5"));
+        assert!(!is_valid("1.2-"));
+        assert!(!is_valid("1.2-+build"));
+        assert!(!is_valid("abc"));
+    }
+
+    #[test]
+    fn compare_ordering() {
+        let versions = [
+            "1.2-alpha",
+            "1.2-rc.1",
+            "1.2-rc.2",
+            "1.2-1",
+            "1.2-3",
+            "1.2-3.4_5",
+            "1.2",
+            "1.2.1-rc",
+            "1.2.1",
+            "1.2.3.4",
+            "1.10",
+        ];
+
+        for (i, a) in versions.iter().enumerate() {
+            for (j, b) in versions.iter().enumerate() {
+                assert_eq!(
+                    compare(a, b),
+                    Some(i.cmp(&j).into()),
+                    "Testing that {} is {} {}",
+                    a,
+                    Cmp::from(i.cmp(&j)).sign(),
+                    b,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compare_equal() {
+        assert_eq!(compare("1.2", "1.2.0"), Some(Cmp::Eq));
+        assert_eq!(compare("1.2_3", "1.2.3"), Some(Cmp::Eq));
+        assert_eq!(compare("1.2-3.4_5", "1.2-3.4.5"), Some(Cmp::Eq));
+        assert_eq!(compare("1.2+a", "1.2+b"), Some(Cmp::Eq));
+        assert_eq!(compare("1.2", "1.2-"), None);
+    }
+}
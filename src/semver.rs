@@ -0,0 +1,94 @@
+//! Interoperability with the [`semver`](https://crates.io/crates/semver) crate.
+//!
+//! Enabled with the `semver` feature. This allows comparing a `Version` directly with a
+//! `semver::Version`, which is convenient when migrating between both crates.
+
+use std::cmp::Ordering;
+
+use crate::Version;
+
+/// Build a version string for the given semver version, for comparison purposes.
+///
+/// Build metadata is left out, as it has no effect on precedence.
+fn semver_str(version: &::semver::Version) -> String {
+    if version.pre.is_empty() {
+        format!("{}.{}.{}", version.major, version.minor, version.patch)
+    } else {
+        format!(
+            "{}.{}.{}-{}",
+            version.major, version.minor, version.patch, version.pre,
+        )
+    }
+}
+
+/// Compare a version with a semver version, using the rules of this library.
+fn compare_semver(version: &Version, other: &::semver::Version) -> Option<Ordering> {
+    let other = semver_str(other);
+    let other = Version::from(&other)?;
+    version.compare(other).ord()
+}
+
+impl<'a> PartialEq<::semver::Version> for Version<'a> {
+    fn eq(&self, other: &::semver::Version) -> bool {
+        compare_semver(self, other) == Some(Ordering::Equal)
+    }
+}
+
+impl<'a> PartialOrd<::semver::Version> for Version<'a> {
+    fn partial_cmp(&self, other: &::semver::Version) -> Option<Ordering> {
+        compare_semver(self, other)
+    }
+}
+
+impl<'a> PartialEq<Version<'a>> for ::semver::Version {
+    fn eq(&self, other: &Version<'a>) -> bool {
+        compare_semver(other, self) == Some(Ordering::Equal)
+    }
+}
+
+impl<'a> PartialOrd<Version<'a>> for ::semver::Version {
+    fn partial_cmp(&self, other: &Version<'a>) -> Option<Ordering> {
+        compare_semver(other, self).map(Ordering::reverse)
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::Version;
+
+    #[test]
+    fn partial_cmp() {
+        let a = Version::from("1.2.3").unwrap();
+        let b = semver::Version::parse("1.2.4").unwrap();
+
+        assert!(a < b);
+        assert!(a <= b);
+        assert!(a != b);
+        assert!(b > a);
+        assert!(b >= a);
+        assert!(b != a);
+
+        assert!(Version::from("1.2").unwrap() < semver::Version::parse("1.2.1").unwrap());
+        assert!(Version::from("1.3").unwrap() > semver::Version::parse("1.2.9").unwrap());
+    }
+
+    #[test]
+    fn partial_eq() {
+        let a = Version::from("1.2.0").unwrap();
+        let b = semver::Version::parse("1.2.0").unwrap();
+        assert!(a == b);
+        assert!(b == a);
+
+        // Trailing zeros and build metadata have no effect
+        assert!(Version::from("1.2").unwrap() == semver::Version::parse("1.2.0+build.5").unwrap());
+    }
+
+    #[test]
+    fn pre_release() {
+        let rc = semver::Version::parse("1.2.0-rc.1").unwrap();
+        assert!(Version::from("1.2.0").unwrap() > rc);
+        assert!(Version::from("1.2.0-rc.1").unwrap() == rc);
+        assert!(Version::from("1.1.9").unwrap() < rc);
+    }
+}
@@ -0,0 +1,91 @@
+//! Serialization with the [`serde`](https://crates.io/crates/serde) crate.
+//!
+//! Enabled with the `serde` feature. A `Version` is serialized as its version string. The parsed
+//! parts are serialized as a sequence of numbers and strings, to store the parsed breakdown.
+
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::{OwnedPart, Part, Version};
+
+/// Serialize a version as its version string.
+impl<'a> Serialize for Version<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Serialize a number part as number, and a text part as string.
+impl<'a> Serialize for Part<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::Number(number) => serializer.serialize_i32(*number),
+            Part::Text(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+/// Serialize a number part as number, and a text part as string.
+impl Serialize for OwnedPart {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_part().serialize(serializer)
+    }
+}
+
+/// Serialize the parsed parts of a version, rather than its version string.
+///
+/// Use this with `#[serde(serialize_with = "version_compare::serialize_parts")]` on a `Version`
+/// field to store its parsed breakdown. Numbers are serialized as number and text as string.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{serialize_parts, Version};
+///
+/// let version = Version::from("1.2.alpha").unwrap();
+/// let mut json = Vec::new();
+/// serialize_parts(&version, &mut serde_json::Serializer::new(&mut json)).unwrap();
+///
+/// assert_eq!(String::from_utf8(json).unwrap(), r#"[1,2,"alpha"]"#);
+/// ```
+pub fn serialize_parts<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+    version.parts().serialize(serializer)
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use crate::{OwnedPart, Part, Version};
+
+    #[test]
+    fn serialize_version() {
+        let version = Version::from("1.2.alpha").unwrap();
+        assert_eq!(serde_json::to_string(&version).unwrap(), r#""1.2.alpha""#);
+    }
+
+    #[test]
+    fn serialize_parts() {
+        let version = Version::from("1.2.alpha").unwrap();
+        assert_eq!(
+            serde_json::to_value(version.parts()).unwrap(),
+            serde_json::json!([1, 2, "alpha"]),
+        );
+        assert_eq!(
+            serde_json::to_string(&version.to_parts_owned()).unwrap(),
+            r#"[1,2,"alpha"]"#,
+        );
+
+        let mut json = Vec::new();
+        super::serialize_parts(&version, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(String::from_utf8(json).unwrap(), r#"[1,2,"alpha"]"#);
+    }
+
+    #[test]
+    fn serialize_part() {
+        assert_eq!(serde_json::to_string(&Part::Number(1)).unwrap(), "1");
+        assert_eq!(serde_json::to_string(&Part::Text("1")).unwrap(), r#""1""#);
+        assert_eq!(
+            serde_json::to_string(&OwnedPart::Text("rc".into())).unwrap(),
+            r#""rc""#,
+        );
+    }
+}
@@ -1,10 +1,68 @@
-use crate::{Cmp, Manifest};
+use crate::{Cmp, Manifest, OverflowPolicy};
+
+/// The default manifest configuration, which the manifests below are based on.
+const BASE: Manifest = Manifest {
+    gnu_ordering: false,
+    max_depth: None,
+    ignore_text: false,
+    case_sensitive: false,
+    split_alphanumeric: false,
+    reject_empty: false,
+    strip_product_name: false,
+    compare_numbers_only: false,
+    overflow_policy: OverflowPolicy::AsText,
+    strict: false,
+    underscore_update: false,
+    min_depth: None,
+    prerelease_keywords: None,
+    strict_separators: false,
+    longer_is_greater: false,
+    max_len: None,
+    strip_prefixes: &[],
+    strip_suffixes: &[],
+    thousands_separators: false,
+};
 
 /// A manifest configuration for GNU versions.
 const MANIFEST_GNU: Option<Manifest> = Some(Manifest {
     gnu_ordering: true,
-    max_depth: None,
-    ignore_text: false,
+    ..BASE
+});
+
+/// A manifest configuration for case sensitive text comparison.
+const MANIFEST_CASE_SENSITIVE: Option<Manifest> = Some(Manifest {
+    case_sensitive: true,
+    ..BASE
+});
+
+/// A manifest configuration for splitting alphanumeric parts.
+const MANIFEST_SPLIT_ALPHANUMERIC: Option<Manifest> = Some(Manifest {
+    split_alphanumeric: true,
+    ..BASE
+});
+
+/// A manifest configuration for stripping a leading product name.
+const MANIFEST_STRIP_PRODUCT_NAME: Option<Manifest> = Some(Manifest {
+    strip_product_name: true,
+    ..BASE
+});
+
+/// A manifest configuration for comparing just numbers.
+const MANIFEST_COMPARE_NUMBERS_ONLY: Option<Manifest> = Some(Manifest {
+    compare_numbers_only: true,
+    ..BASE
+});
+
+/// A manifest configuration for trailing underscore update numbers.
+const MANIFEST_UNDERSCORE_UPDATE: Option<Manifest> = Some(Manifest {
+    underscore_update: true,
+    ..BASE
+});
+
+/// A manifest configuration where longer versions are greater.
+const MANIFEST_LONGER_IS_GREATER: Option<Manifest> = Some(Manifest {
+    longer_is_greater: true,
+    ..BASE
 });
 
 /// Struct containing a version number with some meta data.
@@ -40,6 +98,7 @@ pub const VERSIONS: &[Version] = &[
     Version("0.0.1-test.0222426166565421816516584651684351354", 5),
     Version("0.0.1-test.02224261665a", 5),
     Version("0.0.1-test.02224261665d7b1b689816d12f6bcacb", 5),
+    Version("123456789012345678901234567890", 1),
 ];
 
 /// List of version numbers that contain errors with metadata for dynamic tests
@@ -67,7 +126,7 @@ pub struct VersionCombi(
 
 impl VersionCombi {
     /// Get versions.
-    pub fn versions(&self) -> (crate::Version, crate::Version) {
+    pub fn versions(&self) -> (crate::Version<'_>, crate::Version<'_>) {
         match self.3 {
             Some(ref manifest) => (
                 crate::Version::from_manifest(self.0, manifest).unwrap(),
@@ -113,12 +172,60 @@ pub const COMBIS: &[VersionCombi] = &[
     VersionCombi("1.2.3 RC3", "1.2.3 RC1", Cmp::Gt, None),
     VersionCombi("1.2.3a", "1.2.3b", Cmp::Lt, None),
     VersionCombi("1.2.3b", "1.2.3a", Cmp::Gt, None),
-    VersionCombi("1.2.3.dev", "1.2.3.alpha", Cmp::Gt, None),
-    VersionCombi("1.2.3-dev", "1.2.3-alpha", Cmp::Gt, None),
-    VersionCombi("1.2.3.dev.1", "1.2.3.alpha", Cmp::Gt, None),
-    VersionCombi("1.2.3-dev-1", "1.2.3-alpha", Cmp::Gt, None),
+    VersionCombi("1.2.3.dev", "1.2.3.alpha", Cmp::Lt, None),
+    VersionCombi("1.2.3-dev", "1.2.3-alpha", Cmp::Lt, None),
+    VersionCombi("1.2.3.dev.1", "1.2.3.alpha", Cmp::Lt, None),
+    VersionCombi("1.2.3-dev-1", "1.2.3-alpha", Cmp::Lt, None),
     VersionCombi("version-compare 3.2.0 / build 0932", "3.2.5", Cmp::Lt, None),
-    VersionCombi("version-compare 3.2.0 / build 0932", "3.1.1", Cmp::Gt, None),
+    // Text is less than any number
+    VersionCombi("1.2.rc0", "1.2.4", Cmp::Lt, None),
+    VersionCombi("1.2.5", "1.2-alpha", Cmp::Gt, None),
+    VersionCombi("version-compare 3.2.0 / build 0932", "3.1.1", Cmp::Lt, None),
+    VersionCombi(
+        "version-compare 3.2.0 / build 0932",
+        "3.1.1",
+        Cmp::Gt,
+        MANIFEST_STRIP_PRODUCT_NAME,
+    ),
+    VersionCombi("MyApp 3.2.0", "3.2.0", Cmp::Eq, MANIFEST_STRIP_PRODUCT_NAME),
+    VersionCombi(
+        "MyApp 3.2.0",
+        "Other 3.2.0",
+        Cmp::Eq,
+        MANIFEST_STRIP_PRODUCT_NAME,
+    ),
+    VersionCombi("MyApp 3.2.0", "3.10", Cmp::Lt, MANIFEST_STRIP_PRODUCT_NAME),
+    // A trailing build number is a post-release, only breaking ties
+    VersionCombi("3.2.0 / build 0932", "3.2.0 / build 1000", Cmp::Lt, None),
+    VersionCombi("3.2.0 / build 0932", "3.2.1", Cmp::Lt, None),
+    VersionCombi("3.2.0 / build 1000", "3.2.1", Cmp::Lt, None),
+    VersionCombi("3.2.0 / build 0932", "3.2.0", Cmp::Gt, None),
+    VersionCombi("3.2 BUILD 932", "3.2.0 / build 0932", Cmp::Eq, None),
+    VersionCombi("1.2.3-rc1", "1.2.3", Cmp::Eq, MANIFEST_COMPARE_NUMBERS_ONLY),
+    VersionCombi(
+        "1.2.alpha.3",
+        "1.2.beta.3",
+        Cmp::Eq,
+        MANIFEST_COMPARE_NUMBERS_ONLY,
+    ),
+    VersionCombi("1.2.alpha.3", "1.2", Cmp::Gt, MANIFEST_COMPARE_NUMBERS_ONLY),
+    VersionCombi(
+        "1.99999999999999999999",
+        "1.rc.2",
+        Cmp::Gt,
+        MANIFEST_COMPARE_NUMBERS_ONLY,
+    ),
+    VersionCombi("1.2.3_2", "1.2.3", Cmp::Gt, MANIFEST_UNDERSCORE_UPDATE),
+    VersionCombi("1.2.3_2", "1.2.4", Cmp::Lt, MANIFEST_UNDERSCORE_UPDATE),
+    VersionCombi("1.2.3_2", "1.2.3.2", Cmp::Lt, MANIFEST_UNDERSCORE_UPDATE),
+    VersionCombi("1.2.3_2", "1.2.3.0_2", Cmp::Eq, MANIFEST_UNDERSCORE_UPDATE),
+    VersionCombi("1.8.0_9", "1.8.0_151", Cmp::Lt, MANIFEST_UNDERSCORE_UPDATE),
+    VersionCombi("1.2.0", "1.2", Cmp::Gt, MANIFEST_LONGER_IS_GREATER),
+    VersionCombi("1.2", "1.2.0.0", Cmp::Lt, MANIFEST_LONGER_IS_GREATER),
+    VersionCombi("1.2.0", "1.2.0", Cmp::Eq, MANIFEST_LONGER_IS_GREATER),
+    VersionCombi("1.2.0", "1.3", Cmp::Lt, MANIFEST_LONGER_IS_GREATER),
+    VersionCombi("1.2-rc", "1.2", Cmp::Lt, MANIFEST_LONGER_IS_GREATER),
+    VersionCombi("1.2.3_2", "1.2.3.2", Cmp::Eq, None),
     VersionCombi(
         "version-compare 1.4.1 / build 0043",
         "version-compare 1.4.1 / build 0043",
@@ -133,6 +240,16 @@ pub const COMBIS: &[VersionCombi] = &[
     ),
     // Issue: https://github.com/timvisee/version-compare/issues/24
     VersionCombi("7.2p1", "7.1", Cmp::Gt, None),
+    VersionCombi("7.2p1", "7.1", Cmp::Gt, MANIFEST_SPLIT_ALPHANUMERIC),
+    VersionCombi("7.2p1", "7.2p2", Cmp::Lt, MANIFEST_SPLIT_ALPHANUMERIC),
+    VersionCombi("7.2p10", "7.2p2", Cmp::Gt, MANIFEST_SPLIT_ALPHANUMERIC),
+    VersionCombi("7.2p2", "7.2p10", Cmp::Lt, MANIFEST_SPLIT_ALPHANUMERIC),
+    VersionCombi(
+        "1.2.3rc2",
+        "1.2.3rc10",
+        Cmp::Lt,
+        MANIFEST_SPLIT_ALPHANUMERIC,
+    ),
     // GNU style versioning, issue: https://github.com/timvisee/version-compare/issues/27
     VersionCombi("1.1", "1.02", Cmp::Lt, MANIFEST_GNU),
     VersionCombi("1.02", "1.2", Cmp::Lt, MANIFEST_GNU),
@@ -150,6 +267,32 @@ pub const COMBIS: &[VersionCombi] = &[
         Cmp::Lt,
         MANIFEST_GNU,
     ),
+    // Numbers overflowing the number type
+    VersionCombi(
+        "123456789012345678901234567890",
+        "123456789012345678901234567891",
+        Cmp::Lt,
+        None,
+    ),
+    VersionCombi(
+        "123456789012345678901234567890",
+        "99999999999999999999999999999",
+        Cmp::Gt,
+        None,
+    ),
+    VersionCombi(
+        "1.123456789012345678901234567890",
+        "1.000123456789012345678901234567890",
+        Cmp::Eq,
+        None,
+    ),
+    VersionCombi("1.99999999999", "1.100", Cmp::Gt, None),
+    VersionCombi("1.99999999999", "1", Cmp::Gt, None),
+    // Case sensitive text comparison
+    VersionCombi("1.0-RC1", "1.0-rc1", Cmp::Eq, None),
+    VersionCombi("1.0-RC1", "1.0-rc1", Cmp::Lt, MANIFEST_CASE_SENSITIVE),
+    VersionCombi("1.0-rc1", "1.0-RC1", Cmp::Gt, MANIFEST_CASE_SENSITIVE),
+    VersionCombi("1.0-rc1", "1.0-rc1", Cmp::Eq, MANIFEST_CASE_SENSITIVE),
     // TODO: inspect these cases
     VersionCombi("snapshot.1.2.3", "1.2.3.alpha", Cmp::Lt, None),
     VersionCombi("snapshot-1.2.3", "1.2.3-alpha", Cmp::Lt, None),
@@ -162,3 +305,73 @@ pub const COMBIS_ERROR: &[VersionCombi] = &[
     VersionCombi("1.2.3.dev", "dev", Cmp::Eq, None),
     VersionCombi("snapshot", "1", Cmp::Lt, None),
 ];
+
+/// Fragments to build random version strings from, including edge cases such as overflowing
+/// numbers, scheme specific markers and non-ASCII characters.
+const FRAGMENTS: &[&str] = &[
+    "0",
+    "1",
+    "2",
+    "10",
+    "007",
+    "2147483648",
+    "99999999999999999999",
+    "a",
+    "B",
+    "rc",
+    "alpha",
+    "dev",
+    "post",
+    "SNAPSHOT",
+    "build",
+    "p",
+    "r",
+    ".",
+    ".",
+    "-",
+    "_",
+    "~",
+    "+",
+    ":",
+    "!",
+    " ",
+    "é",
+    "日",
+    "١",
+    "²",
+];
+
+/// A seeded pseudo random number generator (xorshift), for reproducible property tests.
+pub struct Rng(u64);
+
+impl Rng {
+    /// Create a generator with the given seed.
+    ///
+    /// The seed is scrambled first, so consecutive seeds give unrelated sequences.
+    pub fn new(seed: u64) -> Self {
+        let mut state = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        Rng((state ^ (state >> 31)).max(1))
+    }
+
+    /// Get the next random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Get a random number below `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Generate a random version-like string.
+    pub fn version(&mut self) -> String {
+        (0..self.below(9))
+            .map(|_| FRAGMENTS[self.below(FRAGMENTS.len())])
+            .collect()
+    }
+}
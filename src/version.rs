@@ -4,13 +4,17 @@
 //! is made. This struct provides many methods and features for easy comparison, probing and other
 //! things.
 
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::cmp::Ordering;
 use std::fmt;
 use std::iter::Peekable;
+use std::ops::Index;
 use std::slice::Iter;
 
-use crate::{Cmp, Manifest, Part};
+use crate::{
+    Channel, Cmp, Manifest, OverflowPolicy, OwnedPart, Part, Scheme, SpelledPart,
+    PRERELEASE_KEYWORDS,
+};
 
 /// Version struct, wrapping a string, providing useful comparison functions.
 ///
@@ -31,9 +35,10 @@ use crate::{Cmp, Manifest, Part};
 /// ```
 #[derive(Clone, Eq)]
 pub struct Version<'a> {
-    version: &'a str,
+    version: Cow<'a, str>,
     parts: Vec<Part<'a>>,
     manifest: Option<&'a Manifest>,
+    scheme: Scheme,
 }
 
 impl<'a> Version<'a> {
@@ -53,9 +58,10 @@ impl<'a> Version<'a> {
     /// ```
     pub fn from(version: &'a str) -> Option<Self> {
         Some(Version {
-            version,
+            version: Cow::Borrowed(version),
             parts: split_version_str(version, None)?,
             manifest: None,
+            scheme: Scheme::Generic,
         })
     }
 
@@ -71,9 +77,10 @@ impl<'a> Version<'a> {
     /// ```
     pub fn from_parts(version: &'a str, parts: Vec<Part<'a>>) -> Self {
         Version {
-            version,
+            version: Cow::Borrowed(version),
             parts,
             manifest: None,
+            scheme: Scheme::Generic,
         }
     }
 
@@ -93,12 +100,161 @@ impl<'a> Version<'a> {
     /// ```
     pub fn from_manifest(version: &'a str, manifest: &'a Manifest) -> Option<Self> {
         Some(Version {
-            version,
+            version: Cow::Borrowed(version),
             parts: split_version_str(version, Some(manifest))?,
             manifest: Some(manifest),
+            scheme: Scheme::Generic,
+        })
+    }
+
+    /// Create a `Version` instance from a version string following the given `scheme`.
+    ///
+    /// The version remembers its scheme, and compares to other versions following it. `None` is
+    /// returned if the version string is invalid for the scheme. Parts are parsed generically,
+    /// and are empty if the version string can't be parsed that way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Scheme, Version};
+    ///
+    /// let a = Version::from_scheme("1.0.dev1", Scheme::Pep440).unwrap();
+    /// let b = Version::from_scheme("1.0a1", Scheme::Pep440).unwrap();
+    ///
+    /// assert_eq!(a.scheme(), Scheme::Pep440);
+    /// assert_eq!(a.compare(&b), Cmp::Lt);
+    /// assert!(Version::from_scheme("1.0 final", Scheme::Pep440).is_none());
+    /// ```
+    pub fn from_scheme(version: &'a str, scheme: Scheme) -> Option<Self> {
+        if !scheme.is_valid(version) {
+            return None;
+        }
+
+        Some(Version {
+            version: Cow::Borrowed(version),
+            parts: split_version_str(version, None).unwrap_or_default(),
+            manifest: None,
+            scheme,
         })
     }
 
+    /// Create a `Version` instance from a version string, following the scheme with the given
+    /// `name`.
+    ///
+    /// This is useful to select a scheme by string, such as from a configuration file. See
+    /// `Scheme::from_name` for the available names, and `ParserRegistry` to select custom
+    /// parsers by name.
+    ///
+    /// `None` is returned if the name isn't recognized, or if the version string is invalid for
+    /// the scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version};
+    ///
+    /// let version = Version::from_named("1!1.0", "pep440").unwrap();
+    ///
+    /// assert_eq!(version.scheme(), Scheme::Pep440);
+    /// assert!(Version::from_named("1.0", "unknown").is_none());
+    /// ```
+    pub fn from_named(version: &'a str, name: &str) -> Option<Self> {
+        Version::from_scheme(version, Scheme::from_name(name).ok()?)
+    }
+
+    /// Create a `Version` instance from the given `text`, trimming surrounding noise.
+    ///
+    /// Leading and trailing whitespace and decorators, such as parentheses, brackets and quotes,
+    /// are trimmed. A `v` or `V` prefix followed by a number is stripped as well. Unlike
+    /// `extract`, the whole remaining text is parsed as version.
+    ///
+    /// `None` is returned if the trimmed version string is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// assert_eq!(Version::trim("(1.2.3)").unwrap().as_str(), "1.2.3");
+    /// assert_eq!(Version::trim(" [v1.2.3-rc1] ").unwrap().as_str(), "1.2.3-rc1");
+    /// ```
+    pub fn trim(text: &'a str) -> Option<Self> {
+        let version = text.trim_matches(|c: char| !c.is_alphanumeric());
+        let version = version
+            .strip_prefix(|c| c == 'v' || c == 'V')
+            .filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+            .unwrap_or(version);
+        Version::from(version)
+    }
+
+    /// Create a `Version` instance from the first version found in the given `text`.
+    ///
+    /// This finds the first run of dot separated numbers having at least two numbers, such as
+    /// `2.3.1`, in arbitrary text like a log line or file name. A directly attached suffix is
+    /// included, being an optional `-` or `+` followed by letters and digits, and any dot
+    /// separated numbers after it, such as `-rc.1`.
+    ///
+    /// `None` is returned if no version is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let version = Version::extract("Release build v2.3.1 (final)").unwrap();
+    /// assert_eq!(version.as_str(), "2.3.1");
+    ///
+    /// let version = Version::extract("package-1.0.0-rc.1.tar.gz").unwrap();
+    /// assert_eq!(version.as_str(), "1.0.0-rc.1");
+    ///
+    /// assert!(Version::extract("no version here, just 42").is_none());
+    /// ```
+    pub fn extract(text: &'a str) -> Option<Self> {
+        let bytes = text.as_bytes();
+        let digits = |at: usize| {
+            bytes[at.min(bytes.len())..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count()
+        };
+
+        // Take dot separated digit runs from the given position, return the end
+        let dotted = |mut at: usize| {
+            while bytes.get(at) == Some(&b'.') && digits(at + 1) > 0 {
+                at += 1 + digits(at + 1);
+            }
+            at
+        };
+
+        let mut start = 0;
+        while start < bytes.len() {
+            let len = digits(start);
+            if len == 0 {
+                start += 1;
+                continue;
+            }
+
+            // Require at least two numbers
+            let end = dotted(start + len);
+            if end == start + len {
+                start = end;
+                continue;
+            }
+
+            // Include a suffix
+            let at = end + matches!(bytes.get(end), Some(b'-' | b'+')) as usize;
+            let suffix = bytes[at..]
+                .iter()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .count();
+            let end = if suffix > 0 { dotted(at + suffix) } else { end };
+
+            return Version::from(&text[start..end]);
+        }
+
+        None
+    }
+
     /// Get the version manifest, if available.
     ///
     /// # Examples
@@ -158,6 +314,44 @@ impl<'a> Version<'a> {
         // TODO: Re-parse the version string, because the manifest might have changed.
     }
 
+    /// Get the version scheme this version follows.
+    ///
+    /// This is `Scheme::Generic` unless the version is created with `Version::from_scheme`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version};
+    ///
+    /// assert_eq!(Version::from("1.2.3").unwrap().scheme(), Scheme::Generic);
+    /// assert_eq!(
+    ///     Version::from_scheme("1.2.3", Scheme::Semver).unwrap().scheme(),
+    ///     Scheme::Semver,
+    /// );
+    /// ```
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Get the epoch of this version.
+    ///
+    /// Only versions parsed with a scheme supporting epochs have an epoch, being
+    /// `Scheme::Pep440` (`1!2.0`), `Scheme::Arch` (`1:2.0`) and `Scheme::Debian` (`1:2.0`). For
+    /// these the epoch is `0` if not specified. `None` is returned for all other versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Scheme, Version};
+    ///
+    /// assert_eq!(Version::from_scheme("1!1.0", Scheme::Pep440).unwrap().epoch(), Some(1));
+    /// assert_eq!(Version::from_scheme("1.0", Scheme::Pep440).unwrap().epoch(), Some(0));
+    /// assert_eq!(Version::from("1!1.0").unwrap().epoch(), None);
+    /// ```
+    pub fn epoch(&self) -> Option<i64> {
+        self.scheme.epoch(&self.version)
+    }
+
     /// Get the original version string.
     ///
     /// # Examples
@@ -170,7 +364,7 @@ impl<'a> Version<'a> {
     /// assert_eq!(ver.as_str(), "1.2.3");
     /// ```
     pub fn as_str(&self) -> &str {
-        self.version
+        &self.version
     }
 
     /// Get a specific version part by it's `index`.
@@ -197,6 +391,25 @@ impl<'a> Version<'a> {
         Ok(self.parts[index])
     }
 
+    /// Get a specific version part by it's `index`, or a zero part if the index is out of bound.
+    ///
+    /// A missing part compares equal to a zero part, so `1.2` equals `1.2.0`. This makes it easy
+    /// to compare the parts of versions of different length at the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, Part};
+    ///
+    /// let ver = Version::from("1.2").unwrap();
+    ///
+    /// assert_eq!(ver.saturating_part(1), Part::Number(2));
+    /// assert_eq!(ver.saturating_part(2), Part::Number(0));
+    /// ```
+    pub fn saturating_part(&self, index: usize) -> Part<'a> {
+        self.parts.get(index).copied().unwrap_or(Part::Number(0))
+    }
+
     /// Get a vector of all version parts.
     ///
     /// # Examples
@@ -216,504 +429,3411 @@ impl<'a> Version<'a> {
         self.parts.as_slice()
     }
 
-    /// Compare this version to the given `other` version using the default `Manifest`.
+    /// Get the number of numeric parts in this version.
     ///
-    /// This method returns one of the following comparison operators:
+    /// # Examples
     ///
-    /// * `Lt`
-    /// * `Eq`
-    /// * `Gt`
+    /// ```
+    /// use version_compare::Version;
     ///
-    /// Other comparison operators can be used when comparing, but aren't returned by this method.
+    /// assert_eq!(Version::from("1.2.3").unwrap().count_numeric(), 3);
+    /// assert_eq!(Version::from("1.2.rc.1").unwrap().count_numeric(), 3);
+    /// ```
+    pub fn count_numeric(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|part| matches!(part, Part::Number(_)))
+            .count()
+    }
+
+    /// Get the number of text parts in this version.
     ///
-    /// # Examples:
+    /// Numbers too large for a number part are kept as text, and are counted as text as well.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use version_compare::{Cmp, Version};
+    /// use version_compare::Version;
     ///
-    /// let a = Version::from("1.2").unwrap();
-    /// let b = Version::from("1.3.2").unwrap();
+    /// assert_eq!(Version::from("1.2.3").unwrap().count_text(), 0);
+    /// assert_eq!(Version::from("1.2.rc.1").unwrap().count_text(), 1);
+    /// ```
+    pub fn count_text(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|part| matches!(part, Part::Text(_)))
+            .count()
+    }
+
+    /// Get a vector of all version parts, owning their text.
+    ///
+    /// Unlike `parts`, the returned parts aren't bound to the lifetime of the version string.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(a.compare(&b), Cmp::Lt);
-    /// assert_eq!(b.compare(&a), Cmp::Gt);
-    /// assert_eq!(a.compare(&a), Cmp::Eq);
     /// ```
-    pub fn compare<V>(&self, other: V) -> Cmp
-    where
-        V: Borrow<Version<'a>>,
-    {
-        compare_iter(
-            self.parts.iter().peekable(),
-            other.borrow().parts.iter().peekable(),
-            self.manifest,
-        )
+    /// use version_compare::{OwnedPart, Version};
+    ///
+    /// let parts = {
+    ///     let version = String::from("1.2-rc");
+    ///     Version::from(&version).unwrap().to_parts_owned()
+    /// };
+    ///
+    /// assert_eq!(parts, [
+    ///     OwnedPart::Number(1),
+    ///     OwnedPart::Number(2),
+    ///     OwnedPart::Text("rc".into()),
+    /// ]);
+    /// ```
+    pub fn to_parts_owned(&self) -> Vec<OwnedPart> {
+        self.parts.iter().copied().map(OwnedPart::from).collect()
     }
 
-    /// Compare this version to the given `other` version,
-    /// and check whether the given comparison operator is valid using the default `Manifest`.
+    /// Get the number of each part of this version, preserving positions.
     ///
-    /// All comparison operators can be used.
+    /// Each number part gives `Some` number, each text part gives `None`. Numeric text, such as
+    /// a number overflowing the number part type, gives its number if it fits.
     ///
-    /// # Examples:
+    /// # Examples
     ///
     /// ```
-    /// use version_compare::{Cmp, Version};
+    /// use version_compare::Version;
     ///
-    /// let a = Version::from("1.2").unwrap();
-    /// let b = Version::from("1.3.2").unwrap();
+    /// let ver = Version::from("1.rc.3").unwrap();
     ///
-    /// assert!(a.compare_to(&b, Cmp::Lt));
-    /// assert!(a.compare_to(&b, Cmp::Le));
-    /// assert!(a.compare_to(&a, Cmp::Eq));
-    /// assert!(a.compare_to(&a, Cmp::Le));
+    /// assert_eq!(ver.segments_numeric(), [Some(1), None, Some(3)]);
     /// ```
-    pub fn compare_to<V>(&self, other: V, operator: Cmp) -> bool
+    pub fn segments_numeric(&self) -> Vec<Option<u64>> {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                Part::Number(n) => u64::try_from(*n).ok(),
+                Part::Text(t) if is_digits(t) => t.parse().ok(),
+                Part::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// Get the parts of this version along with their original spelling and separators.
+    ///
+    /// The parts are parsed from the version string again, following the manifest of this
+    /// version. The text after the last part, such as a trailing separator, is returned as well.
+    /// Joining each separator and text followed by the trailing text reconstructs the version
+    /// string exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Part, Version};
+    ///
+    /// let version = Version::from("1.02-RC1 ").unwrap();
+    /// let (parts, trailing) = version.spelled_parts();
+    ///
+    /// assert_eq!(parts[1].part, Part::Number(2));
+    /// assert_eq!(parts[1].text, "02");
+    /// assert_eq!(parts[2].separator, "-");
+    /// assert_eq!(parts[2].text, "RC1");
+    /// assert_eq!(trailing, " ");
+    ///
+    /// let joined: String = parts.iter().flat_map(|p| [p.separator, p.text]).collect();
+    /// assert_eq!(joined + trailing, version.as_str());
+    /// ```
+    pub fn spelled_parts(&self) -> (Vec<SpelledPart<'_>>, &str) {
+        let version = self.as_str();
+        let spans = split_version_spans(version, self.manifest).unwrap_or_default();
+
+        // Take the separators between the original text of each part
+        let mut end = 0;
+        let parts = spans
+            .into_iter()
+            .map(|(part, text)| {
+                let start = text.as_ptr() as usize - version.as_ptr() as usize;
+                let separator = &version[end..start];
+                end = start + text.len();
+                SpelledPart {
+                    part,
+                    separator,
+                    text,
+                }
+            })
+            .collect();
+
+        (parts, &version[end..])
+    }
+
+    /// Iterate over the parts of this and the `other` version side by side, aligned by index.
+    ///
+    /// The iterator yields as many pairs as the longest version has parts, `None` is yielded for
+    /// the shorter version where it has no more parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, Part};
+    ///
+    /// let a = Version::from("1.2.3").unwrap();
+    /// let b = Version::from("1.3").unwrap();
+    ///
+    /// assert_eq!(a.zip_parts(&b).collect::<Vec<_>>(), [
+    ///     (Some(&Part::Number(1)), Some(&Part::Number(1))),
+    ///     (Some(&Part::Number(2)), Some(&Part::Number(3))),
+    ///     (Some(&Part::Number(3)), None),
+    /// ]);
+    /// ```
+    pub fn zip_parts<'b>(
+        &'b self,
+        other: &'b Version<'a>,
+    ) -> impl Iterator<Item = (Option<&'b Part<'a>>, Option<&'b Part<'a>>)> + 'b {
+        let len = self.parts.len().max(other.parts.len());
+        (0..len).map(move |i| (self.parts.get(i), other.parts.get(i)))
+    }
+
+    /// Get the number of leading parts this and the `other` version have in common.
+    ///
+    /// Parts are compared like when comparing versions, so a missing part equals a zero and
+    /// `1.2` and `1.2.0` share all three parts. This is useful for grouping versions by release
+    /// line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let a = Version::from("1.2.3").unwrap();
+    ///
+    /// assert_eq!(a.common_prefix_len(Version::from("1.2.9").unwrap()), 2);
+    /// assert_eq!(a.common_prefix_len(Version::from("2.2.3").unwrap()), 0);
+    /// ```
+    pub fn common_prefix_len<V>(&self, other: V) -> usize
     where
         V: Borrow<Version<'a>>,
     {
-        match self.compare(other) {
-            Cmp::Eq => matches!(operator, Cmp::Eq | Cmp::Le | Cmp::Ge),
-            Cmp::Lt => matches!(operator, Cmp::Ne | Cmp::Lt | Cmp::Le),
-            Cmp::Gt => matches!(operator, Cmp::Ne | Cmp::Gt | Cmp::Ge),
-            _ => unreachable!(),
-        }
+        let other = other.borrow();
+        let len = self.parts.len().max(other.parts.len());
+        (0..len)
+            .take_while(|&i| {
+                let lhs = self.parts.get(i..=i).unwrap_or_default();
+                let rhs = other.parts.get(i..=i).unwrap_or_default();
+                compare_iter(lhs.iter().peekable(), rhs.iter().peekable(), self.manifest) == Cmp::Eq
+            })
+            .count()
     }
-}
 
-impl<'a> fmt::Display for Version<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.version)
+    /// Check whether this version is zero, having nothing but zero number parts.
+    ///
+    /// This is useful to detect default or uninitialized versions. A version without parts such
+    /// as `""` is zero as well, a version having any text part is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// assert!(Version::from("0.0.0").unwrap().is_zero());
+    /// assert!(Version::from("").unwrap().is_zero());
+    /// assert!(!Version::from("0.0.1").unwrap().is_zero());
+    /// assert!(!Version::from("0.0-rc").unwrap().is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.parts.iter().all(Part::is_empty)
     }
-}
 
-// Show just the version component parts as debug output
-impl<'a> fmt::Debug for Version<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if f.alternate() {
-            write!(f, "{:#?}", self.parts)
-        } else {
-            write!(f, "{:?}", self.parts)
+    /// Get the release portion of this version, stripping any pre-release or metadata suffix.
+    ///
+    /// The release portion is the first run of numeric parts in the version, any leading text
+    /// is skipped. The returned version has a generated dotted version string, and is always
+    /// compared generically as that string doesn't follow the scheme of this version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, Part};
+    ///
+    /// let ver = Version::from("1.2.3-rc1+build").unwrap();
+    /// let release = ver.strip_prerelease();
+    ///
+    /// assert_eq!(release.as_str(), "1.2.3");
+    /// assert_eq!(release.parts(), [
+    ///     Part::Number(1),
+    ///     Part::Number(2),
+    ///     Part::Number(3)
+    /// ]);
+    /// ```
+    #[must_use]
+    pub fn strip_prerelease(&self) -> Version<'a> {
+        // Take the first run of number parts
+        let parts: Vec<Part<'a>> = self
+            .parts
+            .iter()
+            .skip_while(|p| matches!(p, Part::Text(_)))
+            .take_while(|p| matches!(p, Part::Number(_)))
+            .copied()
+            .collect();
+
+        Version {
+            version: Cow::Owned(join_parts(&parts)),
+            parts,
+            manifest: self.manifest,
+            scheme: Scheme::Generic,
         }
     }
-}
 
-/// Implement the partial ordering trait for the version struct, to easily allow version comparison.
-impl<'a> PartialOrd for Version<'a> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.compare(other).ord().unwrap())
-    }
-}
+    /// Get this version with only its number parts, keeping them in order.
+    ///
+    /// Unlike `strip_prerelease`, which stops at the first text part, numbers following text parts
+    /// are kept. So `1.2.rc.3` becomes `1.2.3`. The returned version has a generated dotted
+    /// version string, and is always compared generically like with `strip_prerelease`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let ver = Version::from("1.2.rc.3").unwrap();
+    ///
+    /// assert_eq!(ver.retain_numeric().as_str(), "1.2.3");
+    /// assert_eq!(ver.strip_prerelease().as_str(), "1.2");
+    /// ```
+    #[must_use]
+    pub fn retain_numeric(&self) -> Version<'a> {
+        let parts: Vec<Part<'a>> = self
+            .parts
+            .iter()
+            .filter(|p| matches!(p, Part::Number(_)))
+            .copied()
+            .collect();
 
-/// Implement the partial equality trait for the version struct, to easily allow version comparison.
-impl<'a> PartialEq for Version<'a> {
-    fn eq(&self, other: &Self) -> bool {
-        self.compare_to(other, Cmp::Eq)
+        Version {
+            version: Cow::Owned(join_parts(&parts)),
+            parts,
+            manifest: self.manifest,
+            scheme: Scheme::Generic,
+        }
     }
-}
-
-/// Split the given version string, in it's version parts.
-fn split_version_str<'a>(
-    version: &'a str,
-    manifest: Option<&'a Manifest>,
-) -> Option<Vec<Part<'a>>> {
-    // Split the version string, and create a vector to put the parts in
-    let split = version.split(|c| !char::is_alphanumeric(c));
-    let mut parts = Vec::new();
 
-    // Get the manifest to follow
-    let mut used_manifest = &Manifest::default();
-    if let Some(m) = manifest {
-        used_manifest = m;
+    /// Create a version from the given numbers, with a generated dotted version string.
+    ///
+    /// Numbers saturate to the maximum of the number type, in the version string as well, so
+    /// parsing the version string gives an equal version.
+    fn from_numbers(numbers: &[u64]) -> Version<'a> {
+        let parts: Vec<Part<'a>> = numbers
+            .iter()
+            .map(|n| Part::Number(i32::try_from(*n).unwrap_or(i32::MAX)))
+            .collect();
+        Version {
+            version: Cow::Owned(join_parts(&parts)),
+            parts,
+            manifest: None,
+            scheme: Scheme::Generic,
+        }
     }
 
-    // Loop over the parts, and parse them
-    for part in split {
-        // We may not go over the maximum depth
-        if used_manifest.max_depth.is_some() && parts.len() >= used_manifest.max_depth.unwrap_or(0)
-        {
-            break;
+    /// Get the next release after the release numbers of this version, by incrementing the
+    /// release number at `index` and dropping all numbers after it. For example, `1.2.3-rc1`
+    /// becomes `1.3` at index 1.
+    pub(crate) fn next_release(&self, index: usize) -> Version<'a> {
+        let mut release = self.strip_prerelease();
+        release.parts.resize(index + 1, Part::Number(0));
+        if let Some(Part::Number(n)) = release.parts.last_mut() {
+            *n = n.saturating_add(1);
         }
+        release.version = Cow::Owned(join_parts(&release.parts));
+        release
+    }
 
-        // Skip empty parts
+    /// Get the immediate next release after this version, by incrementing its least significant
+    /// release number.
+    ///
+    /// The release portion is the first run of numeric parts, as with `strip_prerelease`. Its last
+    /// number is incremented, so `1.2.3` becomes `1.2.4`. Pre-release parts are dropped, so
+    /// `1.2.3-rc1` becomes `1.2.4` as well, rather than the `1.2.3` release it precedes. A
+    /// version without release numbers becomes `1`. This is useful to build exclusive upper
+    /// bounds for ranges. The returned version has a generated dotted version string, and is
+    /// always compared generically like with `strip_prerelease`.
+    ///
+    /// A number saturates at the maximum of the number type, so its successor is equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// assert_eq!(Version::from("1.2.3").unwrap().successor().as_str(), "1.2.4");
+    /// assert_eq!(Version::from("1.2.3-rc1").unwrap().successor().as_str(), "1.2.4");
+    /// assert_eq!(Version::from("1.9").unwrap().successor().as_str(), "1.10");
+    /// ```
+    #[must_use]
+    pub fn successor(&self) -> Version<'a> {
+        let len = self.strip_prerelease().parts.len();
+        self.next_release(len.max(1) - 1)
+    }
+
+    /// Get the pre-release parts of this version, following the release portion.
+    ///
+    /// The release portion is the first run of numeric parts in the version, as with
+    /// `strip_prerelease`. All parts after it are returned, such as `alpha` or `rc`, `1`. An empty
+    /// slice is returned for a plain release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, Part};
+    ///
+    /// let ver = Version::from("1.2.3-rc.1").unwrap();
+    /// assert_eq!(ver.pre_release_parts(), [Part::Text("rc"), Part::Number(1)]);
+    ///
+    /// let ver = Version::from("1.2.3").unwrap();
+    /// assert!(ver.pre_release_parts().is_empty());
+    /// ```
+    pub fn pre_release_parts(&self) -> &[Part<'a>] {
+        // Skip leading text and the first run of number parts
+        let start = self
+            .parts
+            .iter()
+            .position(|p| matches!(p, Part::Number(_)))
+            .unwrap_or(self.parts.len());
+        let end = self.parts[start..]
+            .iter()
+            .position(|p| matches!(p, Part::Text(_)))
+            .map_or(self.parts.len(), |i| start + i);
+        &self.parts[end..]
+    }
+
+    /// Get the release portion of this version, to compare it independently.
+    ///
+    /// This is the same as `strip_prerelease`, and pairs with `prerelease` to compare release and
+    /// pre-release portions separately, like semantic versioning precedence does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let ver = Version::from("1.2.3-rc.1").unwrap();
+    ///
+    /// assert_eq!(ver.release(), Version::from("1.2.3").unwrap());
+    /// assert_eq!(ver.release().as_str(), "1.2.3");
+    /// ```
+    #[must_use]
+    pub fn release(&self) -> Version<'a> {
+        self.strip_prerelease()
+    }
+
+    /// Get the pre-release portion of this version as its own version, to compare it
+    /// independently.
+    ///
+    /// The version has the parts returned by `pre_release_parts`, and a generated dotted version
+    /// string. It is always compared generically like with `strip_prerelease`. A plain release has
+    /// an empty pre-release version without parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Version};
+    ///
+    /// let a = Version::from("1.2.3-rc.1").unwrap();
+    /// let b = Version::from("1.2.4-rc.2").unwrap();
+    ///
+    /// assert_eq!(a.prerelease().as_str(), "rc.1");
+    /// assert_eq!(a.prerelease().compare(b.prerelease()), Cmp::Lt);
+    /// assert_eq!(a.release().compare(b.release()), Cmp::Lt);
+    /// ```
+    #[must_use]
+    pub fn prerelease(&self) -> Version<'a> {
+        let parts = self.pre_release_parts().to_vec();
+        Version {
+            version: Cow::Owned(join_parts(&parts)),
+            parts,
+            manifest: self.manifest,
+            scheme: Scheme::Generic,
+        }
+    }
+
+    /// Get the release channel of this version.
+    ///
+    /// The channel is classified by the first text part of the pre-release parts, see
+    /// `pre_release_parts`. A version without pre-release text is `Channel::Stable`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Channel, Version};
+    ///
+    /// assert_eq!(Version::from("1.0-rc1").unwrap().channel(), Channel::Rc);
+    /// assert_eq!(Version::from("1.0.dev3").unwrap().channel(), Channel::Dev);
+    /// assert_eq!(Version::from("1.0").unwrap().channel(), Channel::Stable);
+    /// ```
+    pub fn channel(&self) -> Channel {
+        self.pre_release_parts()
+            .iter()
+            .find_map(|p| match p {
+                Part::Text(text) => Some(Channel::from_text(text)),
+                Part::Number(_) => None,
+            })
+            .unwrap_or(Channel::Stable)
+    }
+
+    /// Get the version string with the pre-release number of this version incremented.
+    ///
+    /// The trailing number of the last pre-release part is incremented, keeping the original
+    /// spelling of the rest of the version string. So `1.0-rc1` becomes `1.0-rc2` and `1.0-rc.9`
+    /// becomes `1.0-rc.10`. If the last part has no trailing number, `.1` is appended to it, so
+    /// `1.0-beta` becomes `1.0-beta.1`. This is useful for release automation.
+    ///
+    /// `None` is returned for a plain release without pre-release parts, see `pre_release_parts`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// assert_eq!(Version::from("1.0-rc1").unwrap().bump_prerelease().unwrap(), "1.0-rc2");
+    /// assert_eq!(Version::from("1.0-beta").unwrap().bump_prerelease().unwrap(), "1.0-beta.1");
+    /// assert_eq!(Version::from("1.0").unwrap().bump_prerelease(), None);
+    /// ```
+    pub fn bump_prerelease(&self) -> Option<String> {
+        if self.pre_release_parts().is_empty() {
+            return None;
+        }
+
+        // Find the trailing digits of the last part in the version string
+        let version = self.as_str();
+        let (parts, trailing) = self.spelled_parts();
+        let text = parts.last()?.text;
+        let end = text.as_ptr() as usize - version.as_ptr() as usize + text.len();
+        let digits = text.bytes().rev().take_while(u8::is_ascii_digit).count();
+
+        if digits == 0 {
+            return Some(format!("{}.1{}", &version[..end], trailing));
+        }
+        let start = end - digits;
+        Some(format!(
+            "{}{}{}",
+            &version[..start],
+            increment_digits(&version[start..end]),
+            trailing,
+        ))
+    }
+
+    /// Get a canonical version string, constructed from the version parts.
+    ///
+    /// Numbers are joined with a `.`, text following a number is separated with a `-`. This is
+    /// useful to display versions created with `Version::from_parts`, as `as_str` and `Display`
+    /// show the original version string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::{Version, Part};
+    ///
+    /// let ver = Version::from("1.2.3 RC 1").unwrap();
+    /// assert_eq!(ver.to_dotted_string(), "1.2.3-RC.1");
+    ///
+    /// let ver = Version::from_parts("", vec![Part::Number(1), Part::Number(2)]);
+    /// assert_eq!(ver.to_dotted_string(), "1.2");
+    /// ```
+    pub fn to_dotted_string(&self) -> String {
+        join_parts(&self.parts)
+    }
+
+    /// Get a key for this version, that sorts like this version compares.
+    ///
+    /// The key is a plain string that can be stored in a database column, so versions can be
+    /// ordered with a plain `ORDER BY`. Sorting keys gives the same order as `compare`, and equal
+    /// versions such as `1.2` and `1.2.0` have an equal key. Numbers are zero-padded to a fixed
+    /// width, text is prefixed so it sorts below numbers.
+    ///
+    /// The key follows the manifest of this version, except for `Manifest::gnu_ordering`. Version
+    /// schemes other than `Scheme::Generic` are not supported. Keys of versions parsed with
+    /// different manifests can't be compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let mut versions = ["1.10", "1.2-rc1", "1.2", "1.9.1"];
+    /// versions.sort_by_key(|v| Version::from(v).unwrap().sort_key());
+    ///
+    /// assert_eq!(versions, ["1.2-rc1", "1.2", "1.9.1", "1.10"]);
+    /// ```
+    pub fn sort_key(&self) -> String {
+        let manifest = self.manifest.copied().unwrap_or_default();
+
+        // Take the parts to compare, and the trailing numbers that only break ties
+        let (parts, ties): (Vec<Part>, [Option<i32>; 2]) = if manifest.compare_numbers_only {
+            let numbers = self.parts.iter().filter(|p| match p {
+                Part::Number(_) => true,
+                Part::Text(t) => is_digits(t),
+            });
+            (numbers.copied().collect(), [None, None])
+        } else {
+            let (parts, build, update) = self.split_release();
+            (parts.to_vec(), [build, update])
+        };
+
+        // Missing parts are zeros, so encode each part along with the run of zeros before it.
+        // Text sorts below the end, a number sorts above it. A longer run of zeros sorts higher
+        // before text, and lower before a number. Text is prefixed with its keyword weight.
+        let mut key = String::new();
+        let mut zeros = 0;
+        for part in parts {
+            let (digits, text) = match part {
+                Part::Number(0) if !manifest.longer_is_greater => {
+                    zeros += 1;
+                    continue;
+                }
+                Part::Number(n) => (n.to_string(), None),
+                Part::Text(t) if is_digits(t) => (t.trim_start_matches('0').to_string(), None),
+                Part::Text(t) => {
+                    let raw = if manifest.case_sensitive { t } else { "" };
+                    let text = match keyword_weight(t, Some(&manifest)) {
+                        Some(w) => {
+                            let number = keyword_number(t).trim_start_matches('0');
+                            format!(
+                                "0{:010}{:04}{}{}",
+                                i64::from(w) - i64::from(i32::MIN),
+                                number.len(),
+                                number,
+                                raw
+                            )
+                        }
+                        None if manifest.case_sensitive => format!("1{}", t),
+                        None => format!("1{}", t.to_lowercase()),
+                    };
+                    (String::new(), Some(text))
+                }
+            };
+            let zeros = std::mem::take(&mut zeros).min(9999);
+            match text {
+                Some(text) => key.push_str(&format!("a{:04}{} ", zeros, text)),
+                None => key.push_str(&format!(
+                    "c{:04}{:04}{}",
+                    9999 - zeros,
+                    digits.len(),
+                    digits
+                )),
+            }
+        }
+        key.push('b');
+
+        // Append the numbers breaking ties
+        for tie in ties {
+            match tie {
+                Some(n) => key.push_str(&format!("1{:010}", n)),
+                None => key.push('0'),
+            }
+        }
+
+        key
+    }
+
+    /// Compare this version to the given `other` version using the default `Manifest`.
+    ///
+    /// This method returns one of the following comparison operators:
+    ///
+    /// * `Lt`
+    /// * `Eq`
+    /// * `Gt`
+    ///
+    /// Other comparison operators can be used when comparing, but aren't returned by this method.
+    ///
+    /// Versions having the same scheme are compared following it, see `Version::scheme`.
+    /// Versions with a different scheme are compared generically, so the result doesn't depend on
+    /// which version is compared to which. Use `try_compare` to fail on versions with different
+    /// schemes instead.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Version};
+    ///
+    /// let a = Version::from("1.2").unwrap();
+    /// let b = Version::from("1.3.2").unwrap();
+    ///
+    /// assert_eq!(a.compare(&b), Cmp::Lt);
+    /// assert_eq!(b.compare(&a), Cmp::Gt);
+    /// assert_eq!(a.compare(&a), Cmp::Eq);
+    /// ```
+    pub fn compare<V>(&self, other: V) -> Cmp
+    where
+        V: Borrow<Version<'a>>,
+    {
+        let other = other.borrow();
+        if self.scheme != Scheme::Generic && self.scheme == other.scheme {
+            if let Ok(cmp) = self.scheme.compare(self.as_str(), other.as_str()) {
+                return cmp;
+            }
+        }
+
+        // Compare just the numbers if specified, including numeric text such as overflowing
+        // numbers
+        if self.manifest.map_or(false, |m| m.compare_numbers_only) {
+            let numbers = |parts: &[Part<'a>]| -> Vec<Part<'a>> {
+                parts
+                    .iter()
+                    .filter(|p| match p {
+                        Part::Number(_) => true,
+                        Part::Text(t) => is_digits(t),
+                    })
+                    .copied()
+                    .collect()
+            };
+            let (lhs, rhs) = (numbers(&self.parts), numbers(&other.parts));
+            return compare_iter(lhs.iter().peekable(), rhs.iter().peekable(), self.manifest);
+        }
+
+        // Compare the release parts, a trailing build and update number only break ties
+        let (lhs, lhs_build, lhs_update) = self.split_release();
+        let (rhs, rhs_build, rhs_update) = other.split_release_with(self.manifest);
+        match compare_iter(lhs.iter().peekable(), rhs.iter().peekable(), self.manifest) {
+            Cmp::Eq => lhs_build
+                .cmp(&rhs_build)
+                .then(lhs_update.cmp(&rhs_update))
+                .into(),
+            cmp => cmp,
+        }
+    }
+
+    /// Compare this version to the given `other` version, only comparing the release parts both
+    /// versions have.
+    ///
+    /// Parts are split like `compare` does, so a trailing build and update number aren't part of
+    /// the compared prefix. These only break ties if both versions have as many release parts.
+    pub(crate) fn compare_prefix(&self, other: &Version<'a>) -> Cmp {
+        let (lhs, lhs_build, lhs_update) = self.split_release();
+        let (rhs, rhs_build, rhs_update) = other.split_release_with(self.manifest);
+        let len = lhs.len().min(rhs.len());
+        let (lhs_prefix, rhs_prefix) = (&lhs[..len], &rhs[..len]);
+        let cmp = compare_iter(
+            lhs_prefix.iter().peekable(),
+            rhs_prefix.iter().peekable(),
+            self.manifest,
+        );
+        if cmp != Cmp::Eq || lhs.len() != rhs.len() {
+            return cmp;
+        }
+
+        // A missing build or update number matches anything
+        let tie = |a: Option<i32>, b: Option<i32>| match (a, b) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => Ordering::Equal,
+        };
+        tie(lhs_build, rhs_build)
+            .then(tie(lhs_update, rhs_update))
+            .into()
+    }
+
+    /// Split the parts of this version into the release parts to compare, and a trailing build
+    /// and update number that only break ties.
+    fn split_release(&self) -> (&[Part<'a>], Option<i32>, Option<i32>) {
+        self.split_release_with(self.manifest)
+    }
+
+    /// Split the parts of this version like `split_release`, following the given `manifest`.
+    fn split_release_with(
+        &self,
+        manifest: Option<&Manifest>,
+    ) -> (&[Part<'a>], Option<i32>, Option<i32>) {
+        // Split a trailing update number if specified
+        let (parts, update) = if manifest.map_or(false, |m| m.underscore_update) {
+            split_update(&self.parts, &self.version)
+        } else {
+            (&self.parts[..], None)
+        };
+        let (parts, build) = split_build(parts);
+        (parts, build, update)
+    }
+
+    /// Compare this version to the given `other` version, ordering parts with the given `cmp`
+    /// closure.
+    ///
+    /// Parts of both versions are aligned by index and passed to `cmp` in order, the first
+    /// non-equal result decides. If one version has fewer parts, its missing parts are passed as
+    /// `Part::Number(0)`. The manifest and scheme of the versions are not used.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use version_compare::{Cmp, Part, Version};
+    ///
+    /// let a = Version::from("1.0-alpha").unwrap();
+    /// let b = Version::from("1.0-beta").unwrap();
+    ///
+    /// // Order text by its length
+    /// let by_length = |lhs: &Part, rhs: &Part| match (lhs, rhs) {
+    ///     (Part::Number(lhs), Part::Number(rhs)) => lhs.cmp(rhs),
+    ///     (Part::Text(lhs), Part::Text(rhs)) => lhs.len().cmp(&rhs.len()),
+    ///     _ => Ordering::Equal,
+    /// };
+    ///
+    /// assert_eq!(a.compare(&b), Cmp::Lt);
+    /// assert_eq!(a.compare_by(&b, by_length), Cmp::Gt);
+    /// ```
+    pub fn compare_by<V, F>(&self, other: V, cmp: F) -> Cmp
+    where
+        V: Borrow<Version<'a>>,
+        F: Fn(&Part, &Part) -> Ordering,
+    {
+        let empty = Part::Number(0);
+        self.zip_parts(other.borrow())
+            .map(|(lhs, rhs)| cmp(lhs.unwrap_or(&empty), rhs.unwrap_or(&empty)))
+            .find(|ord| ord != &Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+            .into()
+    }
+
+    /// Compare this version to the given `other` version, requiring both to follow the same
+    /// scheme.
+    ///
+    /// This is like `compare`, but an error is returned if the versions have a different scheme,
+    /// rather than silently comparing them generically.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Scheme, Version};
+    ///
+    /// let a = Version::from_scheme("1.0.dev1", Scheme::Pep440).unwrap();
+    /// let b = Version::from_scheme("1.0a1", Scheme::Pep440).unwrap();
+    /// let c = Version::from("1.0a1").unwrap();
+    ///
+    /// assert_eq!(a.try_compare(&b), Ok(Cmp::Lt));
+    /// assert_eq!(a.try_compare(&c), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn try_compare<V>(&self, other: V) -> Result<Cmp, ()>
+    where
+        V: Borrow<Version<'a>>,
+    {
+        let other = other.borrow();
+        if self.scheme != other.scheme {
+            return Err(());
+        }
+        Ok(self.compare(other))
+    }
+
+    /// Compare this version to the given `other` version, and check whether the given comparison
+    /// `operator` holds.
+    ///
+    /// All comparison operators can be used. This never fails, as comparing always gives an
+    /// ordering. Like with `compare`, versions with a different scheme are compared generically.
+    /// Use `try_compare_to` to get an error for those instead.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Version};
+    ///
+    /// let a = Version::from("1.2").unwrap();
+    /// let b = Version::from("1.3.2").unwrap();
+    ///
+    /// assert!(a.compare_to(&b, Cmp::Lt));
+    /// assert!(a.compare_to(&b, Cmp::Le));
+    /// assert!(a.compare_to(&a, Cmp::Eq));
+    /// assert!(a.compare_to(&a, Cmp::Le));
+    /// ```
+    pub fn compare_to<V>(&self, other: V, operator: Cmp) -> bool
+    where
+        V: Borrow<Version<'a>>,
+    {
+        satisfies(self.compare(other), operator)
+    }
+
+    /// Compare this version to the given `other` version requiring both to follow the same
+    /// scheme, and check whether the given comparison `operator` holds.
+    ///
+    /// This is like `compare_to`, but an error is returned if the versions have a different
+    /// scheme, see `try_compare`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Scheme, Version};
+    ///
+    /// let a = Version::from_scheme("1.0", Scheme::Pep440).unwrap();
+    /// let b = Version::from_scheme("1.0.post1", Scheme::Pep440).unwrap();
+    /// let c = Version::from("1.0.post1").unwrap();
+    ///
+    /// assert_eq!(a.try_compare_to(&b, Cmp::Lt), Ok(true));
+    /// assert_eq!(a.try_compare_to(&b, Cmp::Ge), Ok(false));
+    /// assert_eq!(a.try_compare_to(&c, Cmp::Lt), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)]
+    pub fn try_compare_to<V>(&self, other: V, operator: Cmp) -> Result<bool, ()>
+    where
+        V: Borrow<Version<'a>>,
+    {
+        Ok(satisfies(self.try_compare(other)?, operator))
+    }
+
+    /// Get all comparison operators that are valid for this version and the given `other`
+    /// version.
+    ///
+    /// The operators are returned in the order `Eq`, `Ne`, `Lt`, `Le`, `Ge`, `Gt`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use version_compare::{Cmp, Version};
+    ///
+    /// let a = Version::from("1.2").unwrap();
+    /// let b = Version::from("1.3.2").unwrap();
+    ///
+    /// assert_eq!(a.relations(&b), [Cmp::Ne, Cmp::Lt, Cmp::Le]);
+    /// assert_eq!(a.relations(&a), [Cmp::Eq, Cmp::Le, Cmp::Ge]);
+    /// ```
+    pub fn relations<V>(&self, other: V) -> Vec<Cmp>
+    where
+        V: Borrow<Version<'a>>,
+    {
+        let cmp = self.compare(other);
+        [Cmp::Eq, Cmp::Ne, Cmp::Lt, Cmp::Le, Cmp::Ge, Cmp::Gt]
+            .iter()
+            .copied()
+            .filter(|operator| satisfies(cmp, *operator))
+            .collect()
+    }
+
+    /// Check whether this version and the given `other` version mean the same release, ignoring
+    /// cosmetic differences.
+    ///
+    /// Versions are compared after stripping a leading `v` or `V` prefix followed by a number,
+    /// such as in `v1.2`. Whitespace and trailing zero parts never matter, so `1.2`, ` 1.2.0 `
+    /// and `1.2.0.0` are all the same. Version strings with a stripped prefix are parsed again.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let a = Version::from("v1.2").unwrap();
+    /// let b = Version::from(" 1.2.0 ").unwrap();
+    ///
+    /// assert!(a.normalized_eq(&b));
+    /// assert!(a != b);
+    /// ```
+    pub fn normalized_eq<V>(&self, other: V) -> bool
+    where
+        V: Borrow<Version<'a>>,
+    {
+        self.strip_prefix().compare(other.borrow().strip_prefix()) == Cmp::Eq
+    }
+
+    /// Get this version without a leading `v` or `V` prefix followed by a number.
+    ///
+    /// The version is returned as is if it has no such prefix, or if parsing it without the
+    /// prefix fails.
+    fn strip_prefix(&self) -> Version<'_> {
+        let version = self.as_str().trim_start();
+        let stripped = version
+            .strip_prefix(|c| c == 'v' || c == 'V')
+            .filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit()));
+        let parts = stripped.and_then(|rest| Some((rest, split_version_str(rest, self.manifest)?)));
+
+        match parts {
+            Some((version, parts)) => Version {
+                version: Cow::Borrowed(version),
+                parts,
+                manifest: self.manifest,
+                scheme: self.scheme,
+            },
+            None => self.clone(),
+        }
+    }
+
+    /// Check whether this version is within the range from `low` to `high`.
+    ///
+    /// If `inclusive` is set, versions equal to `low` or `high` are in range, otherwise they are
+    /// not. This is the same as two `compare_to` calls.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let low = Version::from("1.0").unwrap();
+    /// let high = Version::from("2.0").unwrap();
+    ///
+    /// assert!(Version::from("1.5").unwrap().between(&low, &high, false));
+    /// assert!(Version::from("2.0").unwrap().between(&low, &high, true));
+    /// assert!(!Version::from("2.0").unwrap().between(&low, &high, false));
+    /// ```
+    pub fn between<L, H>(&self, low: L, high: H, inclusive: bool) -> bool
+    where
+        L: Borrow<Version<'a>>,
+        H: Borrow<Version<'a>>,
+    {
+        let (lower, upper) = if inclusive {
+            (Cmp::Ge, Cmp::Le)
+        } else {
+            (Cmp::Gt, Cmp::Lt)
+        };
+        self.compare_to(low, lower) && self.compare_to(high, upper)
+    }
+
+    /// Check whether this version is compatible with the given `other` version, following caret
+    /// requirement rules as used by Cargo and npm.
+    ///
+    /// This version is compatible if it isn't less than `other`, and it doesn't change the left
+    /// most non-zero release number of `other`, or any number before it. For `1.2.3` this means
+    /// the major version must match, for `0.2.3` the major and minor version must match.
+    /// Missing release numbers are treated as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use version_compare::Version;
+    ///
+    /// let a = Version::from("1.2.0").unwrap();
+    /// assert!(Version::from("1.5.0").unwrap().is_compatible_with(&a));
+    /// assert!(!Version::from("2.0.0").unwrap().is_compatible_with(&a));
+    ///
+    /// let b = Version::from("0.1.0").unwrap();
+    /// assert!(!Version::from("0.2.1").unwrap().is_compatible_with(&b));
+    /// ```
+    pub fn is_compatible_with<V>(&self, other: V) -> bool
+    where
+        V: Borrow<Version<'a>>,
+    {
+        let other = other.borrow();
+        if self.compare(other) == Cmp::Lt {
+            return false;
+        }
+
+        // Compare the release numbers up to the first non-zero one of other
+        let (lhs, rhs) = (self.strip_prerelease(), other.strip_prerelease());
+        let number = |version: &Version, i: usize| match version.parts.get(i) {
+            Some(Part::Number(n)) => *n,
+            _ => 0,
+        };
+        let len = rhs.parts.len().max(1);
+        let significant = (0..len)
+            .position(|i| number(&rhs, i) != 0)
+            .unwrap_or(len - 1);
+        (0..=significant).all(|i| number(&lhs, i) == number(&rhs, i))
+    }
+}
+
+impl<'a> fmt::Display for Version<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.version)
+    }
+}
+
+// Show the version string and its component parts as debug output, use the debug output of
+// `Version::parts` to show just the parts
+impl<'a> fmt::Debug for Version<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Version")
+            .field("version", &self.version)
+            .field("parts", &self.parts)
+            .finish()
+    }
+}
+
+/// Index version parts, like `version[0]`.
+///
+/// # Panics
+///
+/// Panics if the `index` is out of bound, like indexing a `Vec` does. Use `Version::part` for a
+/// non-panicking alternative.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::{Version, Part};
+///
+/// let ver = Version::from("1.2.3").unwrap();
+///
+/// assert_eq!(ver[0], Part::Number(1));
+/// assert_eq!(ver[2], Part::Number(3));
+/// ```
+impl<'a> Index<usize> for Version<'a> {
+    type Output = Part<'a>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.parts[index]
+    }
+}
+
+/// Parse a version string, like `Version::from`, keeping the borrowed lifetime.
+///
+/// An error is returned if the version string is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::Version;
+///
+/// let ver = Version::try_from("1.2.3").unwrap();
+/// assert_eq!(ver.as_str(), "1.2.3");
+///
+/// assert!(Version::try_from("abc").is_err());
+/// ```
+impl<'a> TryFrom<&'a str> for Version<'a> {
+    type Error = ();
+
+    fn try_from(version: &'a str) -> Result<Self, Self::Error> {
+        Version::from(version).ok_or(())
+    }
+}
+
+/// Create a version from a single number, like `"1"`.
+///
+/// The inherent `Version::from` parses a version string, so use `into` or `From::from` instead.
+/// Numbers beyond the number part type saturate to its maximum, as with
+/// `OverflowPolicy::Saturate`, in the version string as well.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::Version;
+///
+/// let ver: Version = 3.into();
+/// assert_eq!(ver, Version::from("3").unwrap());
+/// assert_eq!(ver.as_str(), "3");
+/// ```
+impl<'a> From<u64> for Version<'a> {
+    fn from(number: u64) -> Self {
+        Version::from_numbers(&[number])
+    }
+}
+
+/// Create a version from a major, minor and patch number, like `"1.2.3"`.
+///
+/// The inherent `Version::from` parses a version string, so use `into` or `From::from` instead.
+/// Numbers beyond the number part type saturate to its maximum, as with
+/// `OverflowPolicy::Saturate`, in the version string as well.
+///
+/// # Examples
+///
+/// ```
+/// use version_compare::Version;
+///
+/// let ver: Version = (1, 2, 3).into();
+/// assert_eq!(ver, Version::from("1.2.3").unwrap());
+/// assert_eq!(ver.as_str(), "1.2.3");
+/// ```
+impl<'a> From<(u64, u64, u64)> for Version<'a> {
+    fn from((major, minor, patch): (u64, u64, u64)) -> Self {
+        Version::from_numbers(&[major, minor, patch])
+    }
+}
+
+/// Implement the partial ordering trait for the version struct, to easily allow version comparison.
+///
+/// Comparing always gives an ordering, as `compare` only returns `Lt`, `Eq` or `Gt`.
+impl<'a> PartialOrd for Version<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Implement the total ordering trait for the version struct, ordering like `compare`.
+///
+/// This allows using versions in ordered collections, such as a `BinaryHeap` to pop the latest
+/// version first. Equal versions such as `1.2` and `1.2.0` are ordered equally.
+///
+/// This is a total order only for versions sharing one manifest and scheme, except that the PHP
+/// scheme isn't transitive for versions ending with a separator. `compare` follows the
+/// manifest of the left hand side version, and compares versions with a different scheme
+/// generically, which doesn't agree with comparing them following their scheme. Every version in
+/// a collection must therefore share one manifest and scheme, otherwise the order is unspecified
+/// and sorting may panic.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BinaryHeap;
+/// use version_compare::Version;
+///
+/// let mut heap = BinaryHeap::new();
+/// for version in ["1.9.9", "2.0.0", "1.10"] {
+///     heap.push(Version::from(version).unwrap());
+/// }
+///
+/// assert_eq!(heap.pop().unwrap().as_str(), "2.0.0");
+/// assert_eq!(heap.pop().unwrap().as_str(), "1.10");
+/// assert_eq!(heap.pop().unwrap().as_str(), "1.9.9");
+/// ```
+impl<'a> Ord for Version<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ord = self.compare(other).ord();
+        debug_assert!(ord.is_some(), "compare must return Lt, Eq or Gt");
+        ord.unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Implement the partial equality trait for the version struct, to easily allow version comparison.
+impl<'a> PartialEq for Version<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.compare_to(other, Cmp::Eq)
+    }
+}
+
+/// Join the given version parts into a canonical version string.
+///
+/// Numbers are joined with a `.`, text following a number is separated with a `-`.
+fn join_parts(parts: &[Part]) -> String {
+    let mut version = String::new();
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            version.push(match (parts[i - 1], part) {
+                (Part::Number(_), Part::Text(_)) => '-',
+                _ => '.',
+            });
+        }
+        version.push_str(&part.to_string());
+    }
+    version
+}
+
+/// Increment the given string of digits by one, keeping leading zeros.
+///
+/// Digits are incremented as text, so numbers of any length are supported.
+fn increment_digits(digits: &str) -> String {
+    let mut digits = digits.as_bytes().to_vec();
+    for digit in digits.iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            return String::from_utf8(digits).unwrap();
+        }
+    }
+    format!("1{}", String::from_utf8(digits).unwrap())
+}
+
+/// Check whether the given comparison result satisfies the given comparison `operator`.
+///
+/// The comparison result must be `Lt`, `Eq` or `Gt`.
+pub(crate) fn satisfies(cmp: Cmp, operator: Cmp) -> bool {
+    match cmp {
+        Cmp::Eq => matches!(operator, Cmp::Eq | Cmp::Le | Cmp::Ge),
+        Cmp::Lt => matches!(operator, Cmp::Ne | Cmp::Lt | Cmp::Le),
+        Cmp::Gt => matches!(operator, Cmp::Ne | Cmp::Gt | Cmp::Ge),
+        _ => unreachable!(),
+    }
+}
+
+/// Split a trailing `build N` segment from the given version parts.
+///
+/// Returns the remaining parts, and the build number if there was such segment. The build is
+/// matched case insensitively.
+fn split_build<'a, 'b>(parts: &'b [Part<'a>]) -> (&'b [Part<'a>], Option<i32>) {
+    match parts {
+        [rest @ .., Part::Text(text), Part::Number(build)]
+            if text.eq_ignore_ascii_case("build") =>
+        {
+            (rest, Some(*build))
+        }
+        _ => (parts, None),
+    }
+}
+
+/// Split a trailing `_N` update number from the given version parts.
+///
+/// Returns the remaining parts, and the update number if the `version` string ends with such
+/// segment.
+fn split_update<'a, 'b>(parts: &'b [Part<'a>], version: &str) -> (&'b [Part<'a>], Option<i32>) {
+    let update = version
+        .rsplit_once('_')
+        .map_or(false, |(_, n)| is_digits(n));
+    match parts {
+        [rest @ .., Part::Number(update_number)] if update => (rest, Some(*update_number)),
+        _ => (parts, None),
+    }
+}
+
+/// Split the given version string, in it's version parts.
+fn split_version_str<'a>(
+    version: &'a str,
+    manifest: Option<&'a Manifest>,
+) -> Option<Vec<Part<'a>>> {
+    let spans = split_version_spans(version, manifest)?;
+    Some(spans.into_iter().map(|(part, _)| part).collect())
+}
+
+#[cfg(test)]
+thread_local! {
+    /// The number of version strings split on this thread, to test when versions are parsed.
+    pub(crate) static SPLIT_COUNT: std::cell::Cell<usize> = std::cell::Cell::new(0);
+}
+
+/// Split the given version string, in it's version parts along with their original text.
+///
+/// Each original text is a slice of the given version string.
+#[allow(clippy::type_complexity)]
+fn split_version_spans<'a>(
+    version: &'a str,
+    manifest: Option<&'a Manifest>,
+) -> Option<Vec<(Part<'a>, &'a str)>> {
+    #[cfg(test)]
+    SPLIT_COUNT.with(|count| count.set(count.get() + 1));
+
+    // Get the manifest to follow
+    let mut used_manifest = &Manifest::default();
+    if let Some(m) = manifest {
+        used_manifest = m;
+    }
+
+    // Reject long versions if specified
+    if used_manifest
+        .max_len
+        .map_or(false, |max_len| version.len() > max_len)
+    {
+        return None;
+    }
+
+    // Strip the first matching prefix and suffix if specified
+    let version = used_manifest
+        .strip_prefixes
+        .iter()
+        .find_map(|prefix| version.strip_prefix(prefix))
+        .unwrap_or(version);
+    let version = used_manifest
+        .strip_suffixes
+        .iter()
+        .find_map(|suffix| version.strip_suffix(suffix))
+        .unwrap_or(version);
+
+    // Split the version string, and create a vector to put the parts in
+    let split = split_parts(version, used_manifest.thousands_separators);
+    let mut parts = Vec::new();
+
+    // Reject empty versions if specified
+    if used_manifest.reject_empty && !version.chars().any(char::is_alphanumeric) {
+        return None;
+    }
+
+    // Loop over the parts, and parse them
+    let mut stripped = false;
+    for part in split {
+        // Reject empty parts from misplaced separators if specified
+        if used_manifest.strict_separators && part.is_empty() && !version.is_empty() {
+            return None;
+        }
+
+        // We may not go over the maximum depth, reject any excess parts if strict
+        if used_manifest.max_depth.is_some() && parts.len() >= used_manifest.max_depth.unwrap_or(0)
+        {
+            if !used_manifest.strict {
+                break;
+            }
+            if !part.is_empty() {
+                return None;
+            }
+            continue;
+        }
+
+        // Skip empty parts
         if part.is_empty() {
             continue;
         }
 
-        // Try to parse the value as an number
-        match part.parse::<i32>() {
-            Ok(number) => {
-                // For GNU ordering we parse numbers with leading zero as string
-                if number > 0
-                    && part.starts_with('0')
-                    && manifest.map(|m| m.gnu_ordering).unwrap_or(false)
-                {
-                    parts.push(Part::Text(part));
+        // Skip a leading product name if specified
+        if used_manifest.strip_product_name
+            && parts.is_empty()
+            && !part.starts_with(|c: char| c.is_ascii_digit())
+        {
+            stripped = true;
+            continue;
+        }
+
+        // Parse numbers with thousands separators, which are only kept in parts if specified
+        if part.contains(',') {
+            let digits: String = part.chars().filter(|&c| c != ',').collect();
+            if is_digits(&digits) {
+                if used_manifest.strict && is_overflowing_number(&digits) {
+                    return None;
+                }
+                if let Some(number) = parse_number(&digits, used_manifest.overflow_policy).ok()? {
+                    parts.push((Part::Number(number), part));
+                    continue;
+                }
+
+                // Split an overflowing number kept as text at its separators instead
+                for group in part.split(',') {
+                    match parse_number(group, used_manifest.overflow_policy).ok()? {
+                        Some(number) => parts.push((Part::Number(number), group)),
+                        None => parts.push((Part::Text(group), group)),
+                    }
+                }
+                continue;
+            }
+        }
+
+        // Pin or reject numbers overflowing the number type if specified
+        if is_overflowing_number(part) {
+            if used_manifest.strict {
+                return None;
+            }
+            if let Some(number) = parse_number(part, used_manifest.overflow_policy).ok()? {
+                parts.push((Part::Number(number), part));
+                continue;
+            }
+        }
+
+        // Try to parse the value as an number
+        match part.parse::<i32>() {
+            Ok(number) => {
+                // For GNU ordering we parse numbers with leading zero as string
+                if number > 0
+                    && part.starts_with('0')
+                    && manifest.map(|m| m.gnu_ordering).unwrap_or(false)
+                {
+                    parts.push((Part::Text(part), part));
+                    continue;
+                }
+
+                // Push the number part to the vector
+                parts.push((Part::Number(number), part));
+            }
+            Err(_) => {
+                // Text parts are not allowed if strict
+                if used_manifest.strict {
+                    return None;
+                }
+
+                // Split all letter and digit runs if specified
+                if used_manifest.split_alphanumeric {
+                    for part in split_alphanumeric(part, used_manifest.overflow_policy) {
+                        let part = part.ok()?;
+                        if used_manifest.max_depth.is_some()
+                            && parts.len() >= used_manifest.max_depth.unwrap_or(0)
+                        {
+                            break;
+                        }
+                        if used_manifest.ignore_text && matches!(part, (Part::Text(_), _)) {
+                            continue;
+                        }
+                        parts.push(part);
+                    }
+                    continue;
+                }
+
+                // Ignore text parts if specified
+                if used_manifest.ignore_text {
+                    continue;
+                }
+
+                // Numbers suffixed by text should be split into a number and text as well,
+                // if the number overflows, handle it following the overflow policy
+                let split_at = part
+                    .char_indices()
+                    .take(part.len() - 1)
+                    .take_while(|(_, c)| c.is_ascii_digit())
+                    .map(|(i, c)| (i, c, part.chars().nth(i + 1).unwrap()))
+                    .filter(|(_, _, b)| b.is_alphabetic())
+                    .map(|(i, _, _)| i)
+                    .next();
+                if let Some(at) = split_at {
+                    match parse_number(&part[..=at], used_manifest.overflow_policy).ok()? {
+                        Some(n) => {
+                            let (number, text) = part.split_at(at + 1);
+                            parts.push((Part::Number(n), number));
+                            parts.push((Part::Text(text), text));
+                        }
+                        None => parts.push((Part::Text(part), part)),
+                    }
+                    continue;
+                }
+
+                // Push the text part to the vector
+                parts.push((Part::Text(part), part))
+            }
+        }
+    }
+
+    // The version must have at least the minimum depth
+    if parts.len() < used_manifest.min_depth.unwrap_or(0) {
+        return None;
+    }
+
+    // The version must contain more than just a stripped product name
+    if stripped && parts.is_empty() {
+        return None;
+    }
+
+    // The version must contain a number part if any part was parsed
+    if !parts.is_empty()
+        && !parts.iter().any(|(p, _)| match p {
+            Part::Number(_) => true,
+            Part::Text(t) => is_digits(t),
+        })
+    {
+        return None;
+    }
+
+    // Return the list of parts
+    Some(parts)
+}
+
+/// Split the given alphanumeric part into alternating runs of digits and other characters.
+///
+/// For example, `2p10` is split into `2`, `p` and `10`. Digit runs that overflow the number type
+/// are handled following the given overflow `policy`, an error is yielded if they are rejected.
+/// Each part is yielded along with its run.
+fn split_alphanumeric(
+    part: &str,
+    policy: OverflowPolicy,
+) -> impl Iterator<Item = Result<(Part<'_>, &str), ()>> {
+    let mut rest = part;
+    std::iter::from_fn(move || {
+        let digits = rest.chars().next()?.is_ascii_digit();
+        let at = rest
+            .find(|c: char| c.is_ascii_digit() != digits)
+            .unwrap_or(rest.len());
+        let (run, tail) = rest.split_at(at);
+        rest = tail;
+
+        if !digits {
+            return Some(Ok((Part::Text(run), run)));
+        }
+        let part = parse_number(run, policy).map(|n| n.map_or(Part::Text(run), Part::Number));
+        Some(part.map(|part| (part, run)))
+    })
+}
+
+/// Split the given version string at each non-alphanumeric character.
+///
+/// Like `str::split`, this yields empty strings for leading, trailing and repeated separators. If
+/// `thousands` is set, a comma between a digit and a group of exactly three digits does not split,
+/// so `1,024` is kept as a single part.
+fn split_parts(version: &str, thousands: bool) -> Vec<&str> {
+    let bytes = version.as_bytes();
+    let is_thousands = |i: usize| {
+        i > 0
+            && bytes[i - 1].is_ascii_digit()
+            && bytes.len() >= i + 4
+            && bytes[i + 1..i + 4].iter().all(u8::is_ascii_digit)
+            && bytes.get(i + 4).map_or(true, |b| !b.is_ascii_digit())
+    };
+
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for (i, c) in version.char_indices() {
+        if c.is_alphanumeric() || (thousands && c == ',' && is_thousands(i)) {
+            continue;
+        }
+        parts.push(&version[start..i]);
+        start = i + c.len_utf8();
+    }
+    parts.push(&version[start..]);
+    parts
+}
+
+/// Parse the given digits as number, handling overflow following the given `policy`.
+///
+/// `Ok(None)` is returned if an overflowing number must be kept as text, an error is returned if
+/// it is rejected.
+fn parse_number(digits: &str, policy: OverflowPolicy) -> Result<Option<i32>, ()> {
+    match digits.parse() {
+        Ok(n) => Ok(Some(n)),
+        Err(_) => match policy {
+            OverflowPolicy::Error => Err(()),
+            OverflowPolicy::Saturate => Ok(Some(i32::MAX)),
+            OverflowPolicy::AsText => Ok(None),
+        },
+    }
+}
+
+/// Compare two version numbers based on the iterators of their version parts.
+///
+/// This method returns one of the following comparison operators:
+///
+/// * `Lt`
+/// * `Eq`
+/// * `Gt`
+///
+/// Other comparison operators can be used when comparing, but aren't returned by this method.
+fn compare_iter<'a>(
+    mut iter: Peekable<Iter<Part<'a>>>,
+    mut other_iter: Peekable<Iter<Part<'a>>>,
+    manifest: Option<&Manifest>,
+) -> Cmp {
+    // Iterate over the iterator, without consuming it
+    for part in &mut iter {
+        match (part, other_iter.next()) {
+            // If we only have a zero on the lhs, continue, unless longer versions are greater
+            (Part::Number(lhs), None)
+                if lhs == &0 && !manifest.map_or(false, |m| m.longer_is_greater) =>
+            {
+                continue;
+            }
+
+            // If we only have text on the lhs, it is less, unless it is numeric text such as an
+            // overflowing number
+            (Part::Text(lhs), None) if !is_digits(lhs) => return Cmp::Lt,
+
+            // If we have anything else on the lhs, it is greater
+            (_, None) => return Cmp::Gt,
+
+            // Compare numbers
+            (Part::Number(lhs), Some(Part::Number(rhs))) => match Cmp::from(lhs.cmp(rhs)) {
+                Cmp::Eq => {}
+                cmp => return cmp,
+            },
+
+            // Compare numeric text, such as overflowing numbers, by magnitude
+            (Part::Text(lhs), Some(Part::Text(rhs))) if is_digits(lhs) && is_digits(rhs) => {
+                match Cmp::from(compare_digits(lhs, rhs)) {
+                    Cmp::Eq => {}
+                    cmp => return cmp,
+                }
+            }
+
+            // A number overflowing the number type is always greater than a regular number
+            (Part::Number(_), Some(Part::Text(rhs))) if is_overflowing_number(rhs) => {
+                return Cmp::Lt
+            }
+            (Part::Text(lhs), Some(Part::Number(_))) if is_overflowing_number(lhs) => {
+                return Cmp::Gt
+            }
+
+            // Numeric text, such as overflowing numbers, is greater than text, as any number is
+            (Part::Text(lhs), Some(Part::Text(_))) if is_digits(lhs) => return Cmp::Gt,
+            (Part::Text(_), Some(Part::Text(rhs))) if is_digits(rhs) => return Cmp::Lt,
+
+            // Compare text
+            (Part::Text(lhs), Some(Part::Text(rhs))) => {
+                match Cmp::from(compare_text(lhs, rhs, manifest)) {
+                    Cmp::Eq => {}
+                    cmp => return cmp,
+                }
+            }
+
+            // Compare a number and text
+            (lhs @ Part::Number(_), Some(rhs @ Part::Text(_)))
+            | (lhs @ Part::Text(_), Some(rhs @ Part::Number(_))) => {
+                // For GNU ordering we have a special number/text comparison
+                let gnu = match manifest {
+                    Some(m) if m.gnu_ordering => compare_gnu_number_text(lhs, rhs),
+                    _ => None,
+                };
+
+                // Otherwise text is always less, like text is less than nothing: "1.2-dev" < "1.2"
+                match gnu {
+                    Some(Cmp::Eq) => {}
+                    Some(cmp) => return cmp,
+                    None if matches!(lhs, Part::Number(_)) => return Cmp::Gt,
+                    None => return Cmp::Lt,
+                }
+            }
+        }
+    }
+
+    // Check whether we should iterate over the other iterator, if it has any items left
+    match other_iter.peek() {
+        // Compare based on the other iterator
+        Some(_) => compare_iter(other_iter, iter, manifest).flip(),
+
+        // Nothing more to iterate over, the versions should be equal
+        None => Cmp::Eq,
+    }
+}
+
+/// Compare two text parts.
+///
+/// Text being a pre-release keyword is ordered by its weight, before any other text: "alpha" will
+/// be less than "beta". Keywords of equal weight are equal, and are ordered by their trailing
+/// number only: "a1" equals "alpha1", and "RC1" is less than "pre2". Other text is ordered
+/// alphabetically, normalizing case unless configured otherwise: "Final" equals "final".
+///
+/// If configured to be case sensitive, keywords of equal weight and number are ordered by their
+/// raw text as well: "RC1" will be less than "rc1", and "a1" less than "alpha1".
+pub(crate) fn compare_text(lhs: &str, rhs: &str, manifest: Option<&Manifest>) -> Ordering {
+    let case_sensitive = manifest.map_or(false, |m| m.case_sensitive);
+    match (keyword_weight(lhs, manifest), keyword_weight(rhs, manifest)) {
+        (Some(lhs_weight), Some(rhs_weight)) => lhs_weight
+            .cmp(&rhs_weight)
+            .then_with(|| compare_digits(keyword_number(lhs), keyword_number(rhs)))
+            .then_with(|| {
+                if case_sensitive {
+                    lhs.cmp(rhs)
+                } else {
+                    Ordering::Equal
+                }
+            }),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) if case_sensitive => lhs.cmp(rhs),
+        (None, None) => lhs.to_lowercase().cmp(&rhs.to_lowercase()),
+    }
+}
+
+/// Get the trailing number of a pre-release keyword, such as `1` for `rc1`.
+///
+/// The number is empty if the keyword has no trailing digits.
+fn keyword_number(text: &str) -> &str {
+    let name = text.trim_end_matches(|c: char| c.is_ascii_digit());
+    &text[name.len()..]
+}
+
+/// Get the weight of the pre-release keyword the given text is, ignoring case and trailing
+/// digits.
+///
+/// `None` is returned if the text isn't a pre-release keyword.
+fn keyword_weight(text: &str, manifest: Option<&Manifest>) -> Option<i32> {
+    let keywords = manifest
+        .and_then(|m| m.prerelease_keywords)
+        .unwrap_or(PRERELEASE_KEYWORDS);
+    let name = text.trim_end_matches(|c: char| c.is_ascii_digit());
+    keywords
+        .iter()
+        .find(|(keyword, _)| keyword.eq_ignore_ascii_case(name))
+        .map(|(_, weight)| *weight)
+}
+
+/// Check whether the given text consists of just digits.
+pub(crate) fn is_digits(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Check whether the given text is a number that overflows the number type of `Part::Number`.
+pub(crate) fn is_overflowing_number(text: &str) -> bool {
+    is_digits(text) && text.parse::<i32>().is_err()
+}
+
+/// Compare two strings of digits by their numeric magnitude, without parsing them.
+///
+/// Leading zeros are ignored, a number with more digits is greater.
+pub(crate) fn compare_digits(lhs: &str, rhs: &str) -> Ordering {
+    let lhs = lhs.trim_start_matches('0');
+    let rhs = rhs.trim_start_matches('0');
+    lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs))
+}
+
+/// Special logic for comparing a number and text with GNU ordering.
+///
+/// Numbers should be ordered like this:
+///
+/// - 3
+/// - 04
+/// - 4
+// TODO: this is not efficient, find a better method
+fn compare_gnu_number_text(lhs: &Part, rhs: &Part) -> Option<Cmp> {
+    // Both values must be parsable as numbers
+    let lhs_num = match lhs {
+        Part::Number(n) => *n,
+        Part::Text(n) => n.parse().ok()?,
+    };
+    let rhs_num = match rhs {
+        Part::Number(n) => *n,
+        Part::Text(n) => n.parse().ok()?,
+    };
+
+    // Return ordering if numeric values are different
+    match lhs_num.cmp(&rhs_num).into() {
+        Cmp::Eq => {}
+        cmp => return Some(cmp),
+    }
+
+    // Either value must have a leading zero
+    if !matches!(lhs, Part::Text(t) if t.starts_with('0'))
+        && !matches!(rhs, Part::Text(t) if t.starts_with('0'))
+    {
+        return None;
+    }
+
+    let lhs = match lhs {
+        Part::Number(n) => format!("{}", n),
+        Part::Text(n) => n.to_string(),
+    };
+    let rhs = match rhs {
+        Part::Number(n) => format!("{}", n),
+        Part::Text(n) => n.to_string(),
+    };
+
+    Some(lhs.cmp(&rhs).into())
+}
+
+#[cfg_attr(tarpaulin, skip)]
+#[cfg(test)]
+mod tests {
+    use std::cmp;
+
+    use crate::test::{Rng, COMBIS, VERSIONS, VERSIONS_ERROR};
+    use crate::{Channel, Cmp, Manifest, OverflowPolicy, OwnedPart, Part, Scheme};
+
+    use super::Version;
+
+    #[test]
+    // TODO: This doesn't really test whether this method fully works
+    fn from() {
+        // Test whether parsing works for each test version
+        for version in VERSIONS {
+            assert!(Version::from(version.0).is_some());
+        }
+
+        // Test whether parsing works for each test invalid version
+        for version in VERSIONS_ERROR {
+            assert!(Version::from(version.0).is_none());
+        }
+    }
+
+    #[test]
+    // TODO: This doesn't really test whether this method fully works
+    fn from_manifest() {
+        // Create a manifest
+        let manifest = Manifest::default();
+
+        // Test whether parsing works for each test version
+        for version in VERSIONS {
+            assert_eq!(
+                Version::from_manifest(version.0, &manifest)
+                    .unwrap()
+                    .manifest,
+                Some(&manifest)
+            );
+        }
+
+        // Test whether parsing works for each test invalid version
+        for version in VERSIONS_ERROR {
+            assert!(Version::from_manifest(version.0, &manifest).is_none());
+        }
+    }
+
+    #[test]
+    fn from_manifest_reject_empty() {
+        let manifest = Manifest::builder().reject_empty(true).build();
+
+        for version in ["", " . . "] {
+            // Empty versions are valid by default
+            let ver = Version::from(version).unwrap();
+            assert!(ver.parts().is_empty());
+            assert_eq!(ver.compare(Version::from("0.0").unwrap()), Cmp::Eq);
+
+            assert!(Version::from_manifest(version, &manifest).is_none());
+        }
+
+        assert!(Version::from_manifest("1", &manifest).is_some());
+    }
+
+    #[test]
+    fn from_manifest_strip_product_name() {
+        let manifest = Manifest::builder().strip_product_name(true).build();
+
+        assert_eq!(
+            Version::from_manifest("MyApp 3.2.0", &manifest)
+                .unwrap()
+                .parts(),
+            [Part::Number(3), Part::Number(2), Part::Number(0)],
+        );
+        assert_eq!(
+            Version::from_manifest("version-compare 3.2.0 / build 0932", &manifest)
+                .unwrap()
+                .parts(),
+            [
+                Part::Number(3),
+                Part::Number(2),
+                Part::Number(0),
+                Part::Text("build"),
+                Part::Number(932),
+            ],
+        );
+
+        // Just a product name is invalid
+        assert!(Version::from_manifest("MyApp", &manifest).is_none());
+        assert!(Version::from_manifest("", &manifest).is_some());
+    }
+
+    #[test]
+    fn from_manifest_strict() {
+        let manifest = Manifest::builder().strict(true).build();
+
+        for version in ["1.2.3", "1.2.3.4.5", "01.2", "1..2", "1.2147483647", ""] {
+            assert_eq!(
+                Version::from_manifest(version, &manifest).map(|v| v.parts().to_vec()),
+                Version::from(version).map(|v| v.parts().to_vec()),
+                "{} must parse the same when strict",
+                version,
+            );
+        }
+
+        // Text and overflowing numbers are rejected
+        for version in [
+            "1.2.3-rc1",
+            "1.2.3 build 5",
+            "v1.2",
+            "1.2a",
+            "1.123456789012345678901234567890",
+            "1.123456789012345678901234567890a",
+        ] {
+            assert!(Version::from(version).is_some());
+            assert!(
+                Version::from_manifest(version, &manifest).is_none(),
+                "{} must be rejected when strict",
+                version,
+            );
+        }
+
+        // Excess parts are rejected
+        let manifest = Manifest::builder().strict(true).max_depth(2).build();
+        assert!(Version::from_manifest("1.2", &manifest).is_some());
+        assert!(Version::from_manifest("1.2.", &manifest).is_some());
+        assert!(Version::from_manifest("1.2.0", &manifest).is_none());
+        assert!(Version::from_manifest("1..2.3", &manifest).is_none());
+
+        // Strict takes precedence over other options
+        let manifest = Manifest::builder()
+            .strict(true)
+            .ignore_text(true)
+            .split_alphanumeric(true)
+            .overflow_policy(OverflowPolicy::Saturate)
+            .build();
+        assert!(Version::from_manifest("1.2-rc1", &manifest).is_none());
+        assert!(Version::from_manifest("1.2p1", &manifest).is_none());
+        assert!(Version::from_manifest("1.99999999999999999999", &manifest).is_none());
+    }
+
+    #[test]
+    fn from_manifest_min_depth() {
+        let manifest = Manifest::builder().min_depth(3).build();
+
+        // Below the minimum
+        for version in ["", "1", "1.2", "1.2.", "v1.2"] {
+            assert!(Version::from(version).is_some());
+            assert!(
+                Version::from_manifest(version, &manifest).is_none(),
+                "{} must be rejected",
+                version,
+            );
+        }
+
+        // At and above the minimum
+        for version in ["1.2.3", "1.2.0", "1.2-rc", "1.2.3.4", "1.2.3-rc.1"] {
+            assert!(
+                Version::from_manifest(version, &manifest).is_some(),
+                "{} must be accepted",
+                version,
+            );
+        }
+
+        // The minimum applies after limiting the maximum depth
+        let manifest = Manifest::builder().min_depth(3).max_depth(2).build();
+        assert!(Version::from_manifest("1.2.3", &manifest).is_none());
+        let manifest = Manifest::builder().min_depth(2).max_depth(2).build();
+        assert!(Version::from_manifest("1.2.3", &manifest).is_some());
+    }
+
+    #[test]
+    fn from_manifest_strip_affixes() {
+        let manifest = Manifest::builder()
+            .strip_prefixes(&["release-", "v"])
+            .strip_suffixes(&["-final", "-ga"])
+            .build();
+        let version = Version::from("1.2.3").unwrap();
+
+        for text in [
+            "release-1.2.3-final",
+            "release-1.2.3",
+            "1.2.3-final",
+            "v1.2.3-ga",
+            "1.2.3",
+        ] {
+            let parsed = Version::from_manifest(text, &manifest).unwrap();
+            assert_eq!(parsed.parts(), version.parts(), "{}", text);
+            assert_eq!(parsed.as_str(), text);
+        }
+
+        // Only one prefix and suffix is stripped, and only at the ends
+        let parsed = Version::from_manifest("release-v1.2-final-ga", &manifest).unwrap();
+        assert_eq!(
+            parsed.parts(),
+            [Part::Text("v1"), Part::Number(2), Part::Text("final")],
+        );
+        let parsed = Version::from_manifest("1.2-release-3", &manifest).unwrap();
+        assert_eq!(parsed.parts().len(), 4);
+
+        // Prefixes are case sensitive
+        let parsed = Version::from_manifest("Release-1.2.3", &manifest).unwrap();
+        assert_eq!(parsed.part(0), Ok(Part::Text("Release")));
+    }
+
+    #[test]
+    fn from_manifest_max_len() {
+        let manifest = Manifest::builder().max_len(16).build();
+
+        // At and under the limit
+        for version in ["", "1.2.3", "1.2.3-rc.1+build", "1.2.3-rc.1+bui"] {
+            assert!(
+                Version::from_manifest(version, &manifest).is_some(),
+                "{} must be accepted",
+                version,
+            );
+        }
+
+        // Over the limit, such as a commit hash
+        for version in [
+            "1.2.3-rc.1+build5",
+            "3b18e512dba79e4c8300dd08aeb37f8e728b8dad",
+        ] {
+            assert!(Version::from(version).is_some());
+            assert!(
+                Version::from_manifest(version, &manifest).is_none(),
+                "{} must be rejected",
+                version,
+            );
+        }
+    }
+
+    #[test]
+    fn from_manifest_strict_separators() {
+        let manifest = Manifest::builder().strict_separators(true).build();
+
+        // Misplaced separators are skipped by default, and rejected with strict separators
+        for (version, equal) in [
+            ("1..2", "1.2"),
+            (".1", "1"),
+            ("1.", "1"),
+            ("1.2.", "1.2"),
+            ("1.2-.rc", "1.2-rc"),
+            (" 1.2", "1.2"),
+            ("1, 2", "1.2"),
+        ] {
+            assert_eq!(Version::from(version), Version::from(equal));
+            assert!(
+                Version::from_manifest(version, &manifest).is_none(),
+                "{} must be rejected",
+                version,
+            );
+        }
+
+        // Single separators between parts are accepted
+        for version in ["", "1", "1.2", "1.2.3-rc.1", "1.2 build 5", "1,2;3"] {
+            assert!(
+                Version::from_manifest(version, &manifest).is_some(),
+                "{} must be accepted",
+                version,
+            );
+        }
+    }
+
+    #[test]
+    fn compare_longer_is_greater() {
+        let (a, b) = ("1.2", "1.2.0");
+
+        // By default trailing zeros are equal
+        let (lhs, rhs) = (Version::from(a).unwrap(), Version::from(b).unwrap());
+        assert_eq!(lhs.compare(&rhs), Cmp::Eq);
+
+        // With longer versions being greater, the trailing zero is significant
+        let manifest = Manifest::builder().longer_is_greater(true).build();
+        let lhs = Version::from_manifest(a, &manifest).unwrap();
+        let rhs = Version::from_manifest(b, &manifest).unwrap();
+        assert_eq!(lhs.compare(&rhs), Cmp::Lt);
+        assert_eq!(rhs.compare(&lhs), Cmp::Gt);
+
+        // With a minimum depth, the shorter version is rejected
+        let manifest = Manifest::builder().min_depth(3).build();
+        assert!(Version::from_manifest(a, &manifest).is_none());
+        assert!(Version::from_manifest(b, &manifest).is_some());
+    }
+
+    #[test]
+    fn compare_numbers_only() {
+        let manifest = Manifest::builder().compare_numbers_only(true).build();
+        let a = Version::from_manifest("1.2.3-rc1", &manifest).unwrap();
+        let b = Version::from_manifest("1.2.3", &manifest).unwrap();
+
+        assert_eq!(a.compare(&b), Cmp::Eq);
+        assert_eq!(b.compare(&a), Cmp::Eq);
+        assert_eq!(
+            a.parts(),
+            [
+                Part::Number(1),
+                Part::Number(2),
+                Part::Number(3),
+                Part::Text("rc1")
+            ],
+        );
+
+        // Text is compared by default
+        assert_eq!(
+            Version::from("1.2.3-rc1")
+                .unwrap()
+                .compare(Version::from("1.2.3").unwrap()),
+            Cmp::Lt,
+        );
+    }
+
+    #[test]
+    fn manifest() {
+        let manifest = Manifest::default();
+        let mut version = Version::from("1.2.3").unwrap();
+
+        version.manifest = Some(&manifest);
+        assert_eq!(version.manifest(), Some(&manifest));
+
+        version.manifest = None;
+        assert_eq!(version.manifest(), None);
+    }
+
+    #[test]
+    fn has_manifest() {
+        let manifest = Manifest::default();
+        let mut version = Version::from("1.2.3").unwrap();
+
+        version.manifest = Some(&manifest);
+        assert!(version.has_manifest());
+
+        version.manifest = None;
+        assert!(!version.has_manifest());
+    }
+
+    #[test]
+    fn set_manifest() {
+        let manifest = Manifest::default();
+        let mut version = Version::from("1.2.3").unwrap();
+
+        version.set_manifest(Some(&manifest));
+        assert_eq!(version.manifest, Some(&manifest));
+
+        version.set_manifest(None);
+        assert_eq!(version.manifest, None);
+    }
+
+    #[test]
+    fn as_str() {
+        // Test for each test version
+        for version in VERSIONS {
+            // The input version string must be the same as the returned string
+            assert_eq!(Version::from(version.0).unwrap().as_str(), version.0);
+        }
+    }
+
+    #[test]
+    fn part() {
+        // Test for each test version
+        for version in VERSIONS {
+            // Create a version object
+            let ver = Version::from(version.0).unwrap();
+
+            // Loop through each part
+            for i in 0..version.1 {
+                assert_eq!(ver.part(i), Ok(ver.parts[i]));
+            }
+
+            // A value outside the range must return an error
+            assert!(ver.part(version.1).is_err());
+        }
+    }
+
+    #[test]
+    fn trim() {
+        for (text, version) in [
+            ("1.2.3", "1.2.3"),
+            ("(1.2.3)", "1.2.3"),
+            ("[1.2.3]", "1.2.3"),
+            ("[v1.2.3]", "1.2.3"),
+            ("  {V1.2.3-rc1}\n", "1.2.3-rc1"),
+            ("\"1.2 build 5\"", "1.2 build 5"),
+            ("(version 1.2)", "version 1.2"),
+            ("()", ""),
+        ] {
+            let trimmed = Version::trim(text).unwrap();
+            assert_eq!(trimmed.as_str(), version);
+            assert_eq!(trimmed, Version::from(version).unwrap());
+        }
+
+        // Decorators inside the version are kept
+        assert_eq!(Version::trim("(1.2 (beta))").unwrap().as_str(), "1.2 (beta");
+    }
+
+    #[test]
+    fn extract() {
+        for (text, version) in [
+            ("Release build v2.3.1 (final)", "2.3.1"),
+            ("version-compare 2.3.1", "2.3.1"),
+            ("version-compare-2.3.1.tar.gz", "2.3.1"),
+            ("app_2.3.1_amd64.deb", "2.3.1"),
+            ("[2.3.1]", "2.3.1"),
+            ("2.3.1", "2.3.1"),
+            ("Updated to 2.3.1-rc1.", "2.3.1-rc1"),
+            ("Updated to 2.3.1rc1, see notes", "2.3.1rc1"),
+            ("1.0.0-rc.1+build.5", "1.0.0-rc.1"),
+            ("release 3 of 2.3.1", "2.3.1"),
+            ("x86_64 linux 5.15.0-91-generic", "5.15.0-91"),
+            ("2.3.-beta", "2.3"),
+            ("v2.3- final", "2.3"),
+            ("日本 2.3.1 é", "2.3.1"),
+        ] {
+            assert_eq!(
+                Version::extract(text).map(|v| v.as_str().to_string()),
+                Some(version.into()),
+                "extract from {}",
+                text,
+            );
+        }
+
+        for text in ["", "no version", "42", "version 1. 2", ".5", "a.b.c"] {
+            assert!(Version::extract(text).is_none(), "extract from {}", text);
+        }
+
+        assert_eq!(
+            Version::extract("Release build v2.3.1 (final)").unwrap(),
+            Version::from("2.3.1").unwrap(),
+        );
+    }
+
+    #[test]
+    fn saturating_part() {
+        for version in VERSIONS {
+            let ver = Version::from(version.0).unwrap();
+
+            for i in 0..version.1 {
+                assert_eq!(ver.saturating_part(i), ver.parts[i]);
+            }
+
+            // Indices outside the range must return a zero part
+            for i in version.1..version.1 + 3 {
+                assert_eq!(ver.saturating_part(i), Part::Number(0));
+            }
+            assert_eq!(ver.saturating_part(usize::MAX), Part::Number(0));
+        }
+    }
+
+    #[test]
+    fn from_numbers() {
+        let ver: Version = (1, 2, 3).into();
+        assert_eq!(ver, Version::from("1.2.3").unwrap());
+        assert_eq!(ver.as_str(), "1.2.3");
+        assert_eq!(
+            ver.parts(),
+            [Part::Number(1), Part::Number(2), Part::Number(3)]
+        );
+
+        let ver = <Version as From<u64>>::from(7);
+        assert_eq!(ver, Version::from("7").unwrap());
+        assert_eq!(ver, Version::from("7.0.0").unwrap());
+        assert_eq!(ver.as_str(), "7");
+
+        // Numbers saturate to the maximum number part, in the version string as well
+        let ver: Version = (1, u64::MAX, 0).into();
+        assert_eq!(ver.as_str(), "1.2147483647.0");
+        assert_eq!(ver.part(1), Ok(Part::Number(i32::MAX)));
+        assert!(ver > Version::from("1.2147483646.9").unwrap());
+        let ver: Version = (1, 3_000_000_000, 0).into();
+        let max: Version = (1, 2_147_483_647, 5).into();
+        assert!(ver < max);
+
+        // The version string parses to an equal version
+        for numbers in [
+            (1, 2, 3),
+            (0, 0, 0),
+            (1, 3_000_000_000, 0),
+            (u64::MAX, u64::MAX, u64::MAX),
+        ] {
+            let ver: Version = numbers.into();
+            assert_eq!(Version::from(ver.as_str()).unwrap(), ver, "{:?}", numbers);
+            assert_eq!(Version::from(ver.as_str()).unwrap().parts(), ver.parts());
+        }
+        let ver: Version = 3_000_000_000.into();
+        assert_eq!(Version::from(ver.as_str()).unwrap(), ver);
+    }
+
+    #[test]
+    fn try_from() {
+        // Test whether parsing works for each test version
+        for version in VERSIONS {
+            assert_eq!(
+                Version::try_from(version.0).unwrap().parts().len(),
+                version.1
+            );
+        }
+
+        // Test whether parsing fails for each test invalid version
+        for version in VERSIONS_ERROR {
+            assert_eq!(Version::try_from(version.0), Err(()));
+        }
+
+        // Overflowing numbers are kept as text
+        let ver = Version::try_from("1.99999999999999999999").unwrap();
+        assert_eq!(
+            ver.parts(),
+            [Part::Number(1), Part::Text("99999999999999999999")]
+        );
+    }
+
+    #[test]
+    fn index() {
+        // Test for each test version
+        for version in VERSIONS {
+            let ver = Version::from(version.0).unwrap();
+            for i in 0..version.1 {
+                assert_eq!(ver[i], ver.parts[i]);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bound() {
+        let ver = Version::from("1.2.3").unwrap();
+        let _ = ver[3];
+    }
+
+    #[test]
+    fn parts() {
+        // Test for each test version
+        for version in VERSIONS {
+            // The number of parts must match
+            assert_eq!(Version::from(version.0).unwrap().parts().len(), version.1);
+        }
+    }
+
+    #[test]
+    fn count_parts() {
+        for (version, numeric, text) in [
+            ("1.2.3", 3, 0),
+            ("1.2.rc.1", 3, 1),
+            ("1.2.3-rc1", 3, 1),
+            ("v1.2", 1, 1),
+            ("1.99999999999999999999", 1, 1),
+            ("", 0, 0),
+        ] {
+            let ver = Version::from(version).unwrap();
+            assert_eq!(ver.count_numeric(), numeric, "{}", version);
+            assert_eq!(ver.count_text(), text, "{}", version);
+            assert_eq!(numeric + text, ver.parts().len());
+        }
+    }
+
+    #[test]
+    fn parts_whitespace() {
+        // Whitespace around and between separators is ignored
+        for (version, clean) in [
+            ("1. 2 .3", "1.2.3"),
+            (" 1.2.3 ", "1.2.3"),
+            ("1 .2. 3", "1.2.3"),
+            ("\t1.2\n", "1.2"),
+            ("1 2 3", "1.2.3"),
+            ("1.2 - rc1", "1.2-rc1"),
+            ("1 . 2 . rc . 1", "1.2.rc.1"),
+            (" .   -32 . 1", "32.1"),
+            ("1.\u{a0}2", "1.2"),
+        ] {
+            let parsed = Version::from(version).unwrap();
+            let clean = Version::from(clean).unwrap();
+            assert_eq!(parsed.parts(), clean.parts(), "{:?}", version);
+            assert_eq!(parsed, clean);
+        }
+        assert_eq!(
+            Version::from("1. 2 .3").unwrap().parts(),
+            [Part::Number(1), Part::Number(2), Part::Number(3)],
+        );
+    }
+
+    #[test]
+    fn parts_thousands_separators() {
+        let manifest = Manifest::builder().thousands_separators(true).build();
+
+        // Commas are separators by default
+        let version = Version::from("3.2.0 build 1,024").unwrap();
+        assert_eq!(version.part(4), Ok(Part::Number(1)));
+        assert_eq!(version.part(5), Ok(Part::Number(24)));
+
+        let version = Version::from_manifest("3.2.0 build 1,024", &manifest).unwrap();
+        assert_eq!(
+            version.parts(),
+            [
+                Part::Number(3),
+                Part::Number(2),
+                Part::Number(0),
+                Part::Text("build"),
+                Part::Number(1024),
+            ],
+        );
+        assert_eq!(version.spelled_parts().0[4].text, "1,024");
+
+        for (text, parts) in [
+            ("1,024,000", vec![Part::Number(1_024_000)]),
+            ("1.2,345", vec![Part::Number(1), Part::Number(2345)]),
+            (
+                "1,2,3",
+                vec![Part::Number(1), Part::Number(2), Part::Number(3)],
+            ),
+            ("1,0245", vec![Part::Number(1), Part::Number(245)]),
+            ("1,02", vec![Part::Number(1), Part::Number(2)]),
+            ("1, 024", vec![Part::Number(1), Part::Number(24)]),
+            ("rc,123", vec![Part::Text("rc"), Part::Number(123)]),
+        ] {
+            let version = Version::from_manifest(text, &manifest).unwrap();
+            assert_eq!(version.parts(), parts, "{}", text);
+        }
+
+        // Overflowing numbers kept as text are split at the separators
+        let version = Version::from_manifest("1,000,000,000,000", &manifest).unwrap();
+        assert_eq!(
+            version.parts(),
+            Version::from("1,000,000,000,000").unwrap().parts()
+        );
+        let saturate = Manifest::builder()
+            .thousands_separators(true)
+            .overflow_policy(OverflowPolicy::Saturate)
+            .build();
+        let version = Version::from_manifest("1,000,000,000,000", &saturate).unwrap();
+        assert_eq!(version.parts(), [Part::Number(i32::MAX)]);
+        let strict = Manifest::builder()
+            .thousands_separators(true)
+            .strict(true)
+            .build();
+        assert!(Version::from_manifest("1,000,000,000,000", &strict).is_none());
+        assert!(Version::from_manifest("1,000,000", &strict).is_some());
+    }
+
+    #[test]
+    fn parts_max_depth() {
+        // Create a manifest
+        let mut manifest = Manifest::default();
+
+        // Loop through a range of numbers
+        for depth in 0..5 {
+            // Set the maximum depth
+            manifest.max_depth = if depth > 0 { Some(depth) } else { None };
+
+            // Test for each test version with the manifest
+            for version in VERSIONS {
+                // Create a version object, and count it's parts
+                let ver = Version::from_manifest(version.0, &manifest);
+
+                // Some versions might be none, because not all of the start with a number when the
+                // maximum depth is 1. A version string with only text isn't allowed,
+                // resulting in none.
+                if ver.is_none() {
                     continue;
                 }
 
-                // Push the number part to the vector
-                parts.push(Part::Number(number));
-            }
-            Err(_) => {
-                // Ignore text parts if specified
-                if used_manifest.ignore_text {
-                    continue;
+                // Get the part count
+                let count = ver.unwrap().parts().len();
+
+                // The number of parts must match
+                if depth == 0 {
+                    assert_eq!(count, version.1);
+                } else {
+                    assert_eq!(count, cmp::min(version.1, depth));
                 }
+            }
+        }
+    }
 
-                // Numbers suffixed by text should be split into a number and text as well,
-                // if the number overflows, handle it as text
-                let split_at = part
-                    .char_indices()
-                    .take(part.len() - 1)
-                    .take_while(|(_, c)| c.is_ascii_digit())
-                    .map(|(i, c)| (i, c, part.chars().nth(i + 1).unwrap()))
-                    .filter(|(_, _, b)| b.is_alphabetic())
-                    .map(|(i, _, _)| i)
-                    .next();
-                if let Some(at) = split_at {
-                    if let Ok(n) = part[..=at].parse() {
-                        parts.push(Part::Number(n));
-                        parts.push(Part::Text(&part[at + 1..]));
-                    } else {
-                        parts.push(Part::Text(part));
+    #[test]
+    fn parts_ignore_text() {
+        // Create a manifest
+        let mut manifest = Manifest::default();
+
+        // Try this for true and false
+        for ignore in &[true, false] {
+            // Set to ignore text
+            manifest.ignore_text = *ignore;
+
+            // Keep track whether any version passed with text
+            let mut had_text = false;
+
+            // Test each test version
+            for version in VERSIONS {
+                // Create a version instance, and get it's parts
+                let ver = Version::from_manifest(version.0, &manifest).unwrap();
+
+                // Loop through all version parts
+                for part in ver.parts() {
+                    if let Part::Text(_) = part {
+                        // Set the flag
+                        had_text = true;
+
+                        // Break the loop if we already reached text when not ignored
+                        if !ignore {
+                            break;
+                        }
                     }
-                    continue;
                 }
-
-                // Push the text part to the vector
-                parts.push(Part::Text(part))
             }
+
+            // Assert had text
+            assert_eq!(had_text, !ignore);
         }
     }
 
-    // The version must contain a number part if any part was parsed
-    if !parts.is_empty() && !parts.iter().any(|p| matches!(p, Part::Number(_))) {
-        return None;
+    #[test]
+    fn zip_parts() {
+        let a = Version::from("1.2").unwrap();
+        let b = Version::from("1.2.3.4").unwrap();
+
+        assert_eq!(
+            a.zip_parts(&b).collect::<Vec<_>>(),
+            [
+                (Some(&Part::Number(1)), Some(&Part::Number(1))),
+                (Some(&Part::Number(2)), Some(&Part::Number(2))),
+                (None, Some(&Part::Number(3))),
+                (None, Some(&Part::Number(4))),
+            ],
+        );
+        assert_eq!(b.zip_parts(&a).count(), 4);
+        assert_eq!(a.zip_parts(&Version::from("").unwrap()).count(), 2);
     }
 
-    // Return the list of parts
-    Some(parts)
-}
+    #[test]
+    fn strip_prerelease() {
+        let cases = [
+            ("1.2.3", "1.2.3"),
+            ("1.2.3-rc1+build", "1.2.3"),
+            ("1.2-dev", "1.2"),
+            ("1.2.alpha.4", "1.2"),
+            ("7.2p1", "7.2"),
+            ("version-compare 3.2.0 / build 0932", "3.2.0"),
+            ("", ""),
+        ];
+
+        for (version, release) in cases {
+            let ver = Version::from(version).unwrap().strip_prerelease();
+            assert_eq!(ver.as_str(), release);
+            assert_eq!(ver, Version::from(release).unwrap());
+            assert!(ver.parts().iter().all(|p| matches!(p, Part::Number(_))));
+        }
+    }
 
-/// Compare two version numbers based on the iterators of their version parts.
-///
-/// This method returns one of the following comparison operators:
-///
-/// * `Lt`
-/// * `Eq`
-/// * `Gt`
-///
-/// Other comparison operators can be used when comparing, but aren't returned by this method.
-fn compare_iter<'a>(
-    mut iter: Peekable<Iter<Part<'a>>>,
-    mut other_iter: Peekable<Iter<Part<'a>>>,
-    manifest: Option<&Manifest>,
-) -> Cmp {
-    // Iterate over the iterator, without consuming it
-    for part in &mut iter {
-        match (part, other_iter.next()) {
-            // If we only have a zero on the lhs, continue
-            (Part::Number(lhs), None) if lhs == &0 => {
-                continue;
-            }
+    #[test]
+    fn derived_generic() {
+        // Generated version strings don't follow the scheme, so derived versions are generic
+        // rather than reinterpreting the string with the scheme, and the epoch is a plain number
+        let ver = Version::from_scheme("1!1.0rc1", Scheme::Pep440).unwrap();
+        let release = ver.strip_prerelease();
+        let successor = ver.successor();
+        for derived in [
+            &release,
+            &successor,
+            &ver.release(),
+            &ver.retain_numeric(),
+            &ver.prerelease(),
+        ] {
+            assert_eq!(derived.scheme(), Scheme::Generic, "{}", derived);
+            assert_eq!(derived.epoch(), None, "{}", derived);
+        }
+        assert_eq!(release.as_str(), "1.1.0");
+        assert_eq!(release, Version::from("1.1.0").unwrap());
+        assert_eq!(successor.as_str(), "1.1.1");
+        assert_eq!(successor.compare(&release), Cmp::Gt);
+    }
 
-            // If we only have text on the lhs, it is less
-            (Part::Text(_), None) => return Cmp::Lt,
+    #[test]
+    fn successor() {
+        for (version, successor) in [
+            ("1.2.3", "1.2.4"),
+            ("1.2.9", "1.2.10"),
+            ("1", "2"),
+            ("0.0.0", "0.0.1"),
+            ("01.02", "1.3"),
+            ("1.2.3-rc1", "1.2.4"),
+            ("1.2.3.rc.1", "1.2.4"),
+            ("1.2-beta.4", "1.3"),
+            ("v 1.2", "1.3"),
+            ("version-compare 3.2.0 / build 0932", "3.2.1"),
+            ("", "1"),
+        ] {
+            let ver = Version::from(version).unwrap();
+            let next = ver.successor();
+            assert_eq!(next.as_str(), successor);
+            assert_eq!(next.compare(&ver), Cmp::Gt, "{}", version);
+        }
 
-            // If we have anything else on the lhs, it is greater
-            (_, None) => return Cmp::Gt,
+        // The number type saturates
+        let ver = Version::from("1.2147483647").unwrap();
+        assert_eq!(ver.successor().compare(&ver), Cmp::Eq);
+    }
 
-            // Compare numbers
-            (Part::Number(lhs), Some(Part::Number(rhs))) => match Cmp::from(lhs.cmp(rhs)) {
-                Cmp::Eq => {}
-                cmp => return cmp,
-            },
+    #[test]
+    fn retain_numeric() {
+        let cases = [
+            ("1.2.3", "1.2.3"),
+            ("1.2.rc.3", "1.2.3"),
+            ("1.2.3-rc1+build", "1.2.3"),
+            ("1.2-beta.4", "1.2.4"),
+            ("version-compare 3.2.0 / build 0932", "3.2.0.932"),
+            ("", ""),
+        ];
+
+        for (version, numeric) in cases {
+            let ver = Version::from(version).unwrap().retain_numeric();
+            assert_eq!(ver.as_str(), numeric);
+            assert_eq!(ver, Version::from(numeric).unwrap());
+            assert!(ver.parts().iter().all(|p| matches!(p, Part::Number(_))));
+        }
 
-            // Compare text
-            (Part::Text(lhs), Some(Part::Text(rhs))) => {
-                // Normalize case and compare text: "RC1" will be less than "RC2"
-                match Cmp::from(lhs.to_lowercase().cmp(&rhs.to_lowercase())) {
-                    Cmp::Eq => {}
-                    cmp => return cmp,
-                }
-            }
+        // Numbers after text are kept, unlike when stripping the pre-release
+        let ver = Version::from("1.2.rc.3").unwrap();
+        assert_eq!(ver.retain_numeric(), Version::from("1.2.3").unwrap());
+        assert_eq!(ver.strip_prerelease(), Version::from("1.2").unwrap());
+        assert_ne!(ver.retain_numeric(), ver.strip_prerelease());
+    }
 
-            // For GNU ordering we have a special number/text comparison
-            (lhs @ Part::Number(_), Some(rhs @ Part::Text(_)))
-            | (lhs @ Part::Text(_), Some(rhs @ Part::Number(_)))
-                if manifest.map(|m| m.gnu_ordering).unwrap_or(false) =>
-            {
-                match compare_gnu_number_text(lhs, rhs) {
-                    Some(Cmp::Eq) | None => {}
-                    Some(cmp) => return cmp,
-                }
-            }
+    #[test]
+    fn pre_release_parts() {
+        assert_eq!(
+            Version::from("1.2.3-rc.1").unwrap().pre_release_parts(),
+            [Part::Text("rc"), Part::Number(1)],
+        );
+        assert_eq!(
+            Version::from("1.2-dev").unwrap().pre_release_parts(),
+            [Part::Text("dev")],
+        );
+        assert_eq!(
+            Version::from("version-compare 3.2.0 / build 0932")
+                .unwrap()
+                .pre_release_parts(),
+            [Part::Text("build"), Part::Number(932)],
+        );
+        assert!(Version::from("1.2.3")
+            .unwrap()
+            .pre_release_parts()
+            .is_empty());
+        assert!(Version::from("").unwrap().pre_release_parts().is_empty());
+    }
+
+    #[test]
+    fn release_prerelease() {
+        let ver = Version::from("1.2.3-rc.1").unwrap();
+        assert_eq!(ver.release(), Version::from("1.2.3").unwrap());
+        assert_eq!(ver.prerelease(), Version::from("rc.1").unwrap());
+        assert_eq!(
+            ver.prerelease().parts(),
+            [Part::Text("rc"), Part::Number(1)]
+        );
 
-            // TODO: decide what to do for other type combinations
-            _ => {}
+        for (version, release, prerelease) in [
+            ("1.2.3", "1.2.3", ""),
+            ("1.2-dev", "1.2", "dev"),
+            ("1.2.3-rc1+build.5", "1.2.3", "rc1.build.5"),
+            ("v 2.0 beta 2", "2.0", "beta.2"),
+            ("", "", ""),
+        ] {
+            let ver = Version::from(version).unwrap();
+            assert_eq!(ver.release().as_str(), release);
+            assert_eq!(ver.prerelease().as_str(), prerelease);
+            assert_eq!(ver.release(), ver.strip_prerelease());
         }
+
+        // Compare the portions independently
+        let a = Version::from("1.2.3-rc.2").unwrap();
+        let b = Version::from("1.2.3.0-rc.10").unwrap();
+        assert_eq!(a.release().compare(b.release()), Cmp::Eq);
+        assert_eq!(a.prerelease().compare(b.prerelease()), Cmp::Lt);
+        assert!(Version::from("1.2.3")
+            .unwrap()
+            .prerelease()
+            .parts()
+            .is_empty());
     }
 
-    // Check whether we should iterate over the other iterator, if it has any items left
-    match other_iter.peek() {
-        // Compare based on the other iterator
-        Some(_) => compare_iter(other_iter, iter, manifest).flip(),
+    #[test]
+    fn channel() {
+        for (version, channel) in [
+            ("1.0", Channel::Stable),
+            ("1.0.0.1", Channel::Stable),
+            ("1.0-rc1", Channel::Rc),
+            ("1.0-RC.2", Channel::Rc),
+            ("1.0.dev3", Channel::Dev),
+            ("1.0-nightly", Channel::Nightly),
+            ("1.0-beta.2", Channel::Beta),
+            ("1.0a1", Channel::Alpha),
+            ("1.0.post1", Channel::Other("post".into())),
+        ] {
+            assert_eq!(Version::from(version).unwrap().channel(), channel);
+        }
+    }
 
-        // Nothing more to iterate over, the versions should be equal
-        None => Cmp::Eq,
+    #[test]
+    fn parts_split_alphanumeric() {
+        let mut manifest = Manifest::default();
+        assert_eq!(
+            Version::from_manifest("7.2p10", &manifest).unwrap().parts(),
+            [Part::Number(7), Part::Number(2), Part::Text("p10")],
+        );
+
+        manifest.split_alphanumeric = true;
+        assert_eq!(
+            Version::from_manifest("7.2p10", &manifest).unwrap().parts(),
+            [
+                Part::Number(7),
+                Part::Number(2),
+                Part::Text("p"),
+                Part::Number(10)
+            ],
+        );
+        assert_eq!(
+            Version::from_manifest("1.0rc2a", &manifest)
+                .unwrap()
+                .parts(),
+            [
+                Part::Number(1),
+                Part::Number(0),
+                Part::Text("rc"),
+                Part::Number(2),
+                Part::Text("a")
+            ],
+        );
+        assert_eq!(
+            Version::from_manifest("1.a99999999999", &manifest)
+                .unwrap()
+                .parts(),
+            [Part::Number(1), Part::Text("a"), Part::Text("99999999999")],
+        );
     }
-}
 
-/// Special logic for comparing a number and text with GNU ordering.
-///
-/// Numbers should be ordered like this:
-///
-/// - 3
-/// - 04
-/// - 4
-// TODO: this is not efficient, find a better method
-fn compare_gnu_number_text(lhs: &Part, rhs: &Part) -> Option<Cmp> {
-    // Both values must be parsable as numbers
-    let lhs_num = match lhs {
-        Part::Number(n) => *n,
-        Part::Text(n) => n.parse().ok()?,
-    };
-    let rhs_num = match rhs {
-        Part::Number(n) => *n,
-        Part::Text(n) => n.parse().ok()?,
-    };
+    #[test]
+    fn bump_prerelease() {
+        for (version, bumped) in [
+            ("1.0-rc1", "1.0-rc2"),
+            ("1.0-beta", "1.0-beta.1"),
+            ("1.0-rc.1", "1.0-rc.2"),
+            ("1.0-rc.09", "1.0-rc.10"),
+            ("1.0-rc.007", "1.0-rc.008"),
+            ("1.0 RC 1 ", "1.0 RC 2 "),
+            ("1.0-alpha.2.beta", "1.0-alpha.2.beta.1"),
+            ("2.0.dev", "2.0.dev.1"),
+        ] {
+            let version = Version::from(version).unwrap();
+            assert_eq!(version.bump_prerelease().unwrap(), bumped);
+
+            // The bumped version is greater
+            let bumped = Version::from(bumped).unwrap();
+            assert_eq!(bumped.compare(&version), Cmp::Gt, "{}", bumped);
+        }
 
-    // Return ordering if numeric values are different
-    match lhs_num.cmp(&rhs_num).into() {
-        Cmp::Eq => {}
-        cmp => return Some(cmp),
+        // Digits are incremented as text, with a carry
+        for (version, bumped) in [
+            ("1.0-rc9", "1.0-rc10"),
+            ("1.0-rc.99999999999", "1.0-rc.100000000000"),
+        ] {
+            let version = Version::from(version).unwrap();
+            assert_eq!(version.bump_prerelease().unwrap(), bumped);
+        }
+
+        // Plain releases
+        for version in ["1.0", "1.2.3", ""] {
+            assert!(Version::from(version).unwrap().bump_prerelease().is_none());
+        }
     }
 
-    // Either value must have a leading zero
-    if !matches!(lhs, Part::Text(t) if t.starts_with('0'))
-        && !matches!(rhs, Part::Text(t) if t.starts_with('0'))
-    {
-        return None;
+    #[test]
+    fn to_dotted_string() {
+        let ver = Version::from_parts(
+            "",
+            vec![
+                Part::Number(1),
+                Part::Number(2),
+                Part::Text("rc"),
+                Part::Number(1),
+            ],
+        );
+        assert_eq!(ver.to_dotted_string(), "1.2-rc.1");
+
+        let cases = [
+            ("1.2.3", "1.2.3"),
+            ("", ""),
+            ("1.2.3a", "1.2.3-a"),
+            ("1.2.alpha.dev.4", "1.2-alpha.dev.4"),
+            ("snapshot-1.2", "snapshot.1.2"),
+        ];
+        for (version, dotted) in cases {
+            assert_eq!(Version::from(version).unwrap().to_dotted_string(), dotted);
+        }
+
+        // The canonical version string parses to the same parts
+        for version in VERSIONS {
+            let ver = Version::from(version.0).unwrap();
+            let dotted = ver.to_dotted_string();
+            assert_eq!(Version::from(&dotted).unwrap().parts(), ver.parts());
+        }
     }
 
-    let lhs = match lhs {
-        Part::Number(n) => format!("{}", n),
-        Part::Text(n) => n.to_string(),
-    };
-    let rhs = match rhs {
-        Part::Number(n) => format!("{}", n),
-        Part::Text(n) => n.to_string(),
-    };
+    #[test]
+    fn compare() {
+        // Compare each version in the version set
+        for entry in COMBIS {
+            // Get both versions
+            let (a, b) = entry.versions();
 
-    Some(lhs.cmp(&rhs).into())
-}
+            // Compare them
+            assert_eq!(
+                a.compare(b),
+                entry.2.clone(),
+                "Testing that {} is {} {}",
+                entry.0,
+                entry.2.sign(),
+                entry.1,
+            );
+        }
+    }
 
-#[cfg_attr(tarpaulin, skip)]
-#[cfg(test)]
-mod tests {
-    use std::cmp;
+    #[test]
+    fn segments_numeric() {
+        for (version, numbers) in [
+            ("1.2.3", vec![Some(1), Some(2), Some(3)]),
+            ("1.rc.3", vec![Some(1), None, Some(3)]),
+            ("1.2.3-rc1", vec![Some(1), Some(2), Some(3), None]),
+            ("v1.2", vec![None, Some(2)]),
+            ("1.99999999999", vec![Some(1), Some(99999999999)]),
+            ("1.99999999999999999999", vec![Some(1), None]),
+            ("", vec![]),
+        ] {
+            let ver = Version::from(version).unwrap();
+            assert_eq!(ver.segments_numeric(), numbers, "{}", version);
+            assert_eq!(ver.segments_numeric().len(), ver.parts().len());
+        }
+    }
+
+    #[test]
+    fn to_parts_owned() {
+        // Owned parts outlive the version string
+        let parts = {
+            let version = String::from("1.2.3-beta.4");
+            Version::from(&version).unwrap().to_parts_owned()
+        };
+        assert_eq!(
+            parts,
+            [
+                OwnedPart::Number(1),
+                OwnedPart::Number(2),
+                OwnedPart::Number(3),
+                OwnedPart::Text("beta".into()),
+                OwnedPart::Number(4),
+            ],
+        );
+
+        // Owned parts match the borrowed parts for each version in the version set
+        for version in VERSIONS {
+            let version = Version::from(version.0).unwrap();
+            let owned = version.to_parts_owned();
+            let borrowed: Vec<Part> = owned.iter().map(OwnedPart::as_part).collect();
+            assert_eq!(borrowed, version.parts());
+        }
+    }
 
-    use crate::test::{COMBIS, VERSIONS, VERSIONS_ERROR};
-    use crate::{Cmp, Manifest, Part};
+    #[test]
+    fn overflow_policy() {
+        let version = "1.123456789012345678901234567890.2";
+        let suffixed = "1.123456789012345678901234567890rc.2";
 
-    use super::Version;
+        // Keep as text by default, compared by magnitude
+        let manifest = Manifest::default();
+        assert_eq!(manifest.overflow_policy, OverflowPolicy::AsText);
+        let parsed = Version::from_manifest(version, &manifest).unwrap();
+        assert_eq!(
+            parsed.parts()[1],
+            Part::Text("123456789012345678901234567890")
+        );
+        assert_eq!(
+            parsed.compare(Version::from("1.2147483647").unwrap()),
+            Cmp::Gt
+        );
+        assert_eq!(
+            Version::from_manifest(suffixed, &manifest).unwrap().parts()[1],
+            Part::Text("123456789012345678901234567890rc"),
+        );
 
-    #[test]
-    // TODO: This doesn't really test whether this method fully works
-    fn from() {
-        // Test whether parsing works for each test version
-        for version in VERSIONS {
-            assert!(Version::from(version.0).is_some());
+        // Saturate to the maximum number
+        let manifest = Manifest::builder()
+            .overflow_policy(OverflowPolicy::Saturate)
+            .build();
+        let parsed = Version::from_manifest(version, &manifest).unwrap();
+        assert_eq!(
+            parsed.parts(),
+            [Part::Number(1), Part::Number(i32::MAX), Part::Number(2)],
+        );
+        assert_eq!(
+            parsed.compare(Version::from("1.2147483647.2").unwrap()),
+            Cmp::Eq
+        );
+        assert_eq!(
+            Version::from_manifest(suffixed, &manifest).unwrap().parts()[1..3],
+            [Part::Number(i32::MAX), Part::Text("rc")],
+        );
+
+        // Reject the version
+        let manifest = Manifest::builder()
+            .overflow_policy(OverflowPolicy::Error)
+            .build();
+        assert!(Version::from_manifest(version, &manifest).is_none());
+        assert!(Version::from_manifest(suffixed, &manifest).is_none());
+        assert!(Version::from_manifest("1.2147483647", &manifest).is_some());
+
+        // Each policy applies when splitting alphanumeric parts
+        for (policy, part) in [
+            (
+                OverflowPolicy::AsText,
+                Some(Part::Text("123456789012345678901234567890")),
+            ),
+            (OverflowPolicy::Saturate, Some(Part::Number(i32::MAX))),
+            (OverflowPolicy::Error, None),
+        ] {
+            let manifest = Manifest::builder()
+                .split_alphanumeric(true)
+                .overflow_policy(policy)
+                .build();
+            let parsed = Version::from_manifest("1.p123456789012345678901234567890", &manifest);
+            assert_eq!(parsed.map(|v| v.parts()[2]), part);
         }
+    }
 
-        // Test whether parsing works for each test invalid version
-        for version in VERSIONS_ERROR {
-            assert!(Version::from(version.0).is_none());
+    #[test]
+    fn common_prefix_len() {
+        for (a, b, len) in [
+            ("1.2.3", "1.2.9", 2),
+            ("1.2.3", "1.2.3", 3),
+            ("1.2", "1.2.0", 3),
+            ("1.2.0.0", "1.2", 4),
+            ("1.2.3", "1.3.3", 1),
+            ("1.2.3", "2.2.3", 0),
+            ("1.2-RC.1", "1.2-rc.2", 3),
+            ("1.2-rc1", "1.2", 2),
+            ("", "", 0),
+        ] {
+            let (a, b) = (Version::from(a).unwrap(), Version::from(b).unwrap());
+            assert_eq!(a.common_prefix_len(&b), len, "{} and {}", a, b);
+            assert_eq!(b.common_prefix_len(&a), len, "{} and {}", b, a);
         }
     }
 
     #[test]
-    // TODO: This doesn't really test whether this method fully works
-    fn from_manifest() {
-        // Create a manifest
-        let manifest = Manifest::default();
+    fn is_zero() {
+        for version in ["0", "0.0.0", "", "00.000", "0-0", " . "] {
+            assert!(Version::from(version).unwrap().is_zero(), "{}", version);
+        }
+        for version in [
+            "0.0.1",
+            "1",
+            "0.0-rc",
+            "0 build 0",
+            "0.0.123456789012345678901234567890",
+        ] {
+            assert!(!Version::from(version).unwrap().is_zero(), "{}", version);
+        }
 
-        // Test whether parsing works for each test version
+        // Zero versions compare equal to zero
+        let zero = Version::from("0").unwrap();
         for version in VERSIONS {
+            let version = Version::from(version.0).unwrap();
             assert_eq!(
-                Version::from_manifest(version.0, &manifest)
-                    .unwrap()
-                    .manifest,
-                Some(&manifest)
+                version.is_zero(),
+                version.compare(&zero) == Cmp::Eq,
+                "{}",
+                version
             );
         }
+    }
 
-        // Test whether parsing works for each test invalid version
-        for version in VERSIONS_ERROR {
-            assert!(Version::from_manifest(version.0, &manifest).is_none());
+    #[test]
+    fn spelled_parts() {
+        let manifests = [
+            Manifest::default(),
+            Manifest::builder().max_depth(2).build(),
+            Manifest::builder().ignore_text(true).build(),
+            Manifest::builder().gnu_ordering(true).build(),
+            Manifest::builder().split_alphanumeric(true).build(),
+            Manifest::builder().strip_product_name(true).build(),
+        ];
+        let odd = [
+            "",
+            " . ",
+            "v01.002-RC1",
+            "  1..2__3  ",
+            "1.2p10",
+            "MyApp 3.2.0 / build 0932",
+            "1.123456789012345678901234567890rc",
+            "1.é.日.2",
+        ];
+
+        // Reconstruct each version, and match the parsed parts
+        for manifest in &manifests {
+            for version in VERSIONS.iter().map(|v| v.0).chain(odd) {
+                let version = match Version::from_manifest(version, manifest) {
+                    Some(version) => version,
+                    None => continue,
+                };
+                let (parts, trailing) = version.spelled_parts();
+                let joined: String = parts.iter().flat_map(|p| [p.separator, p.text]).collect();
+                assert_eq!(joined + trailing, version.as_str());
+
+                let parsed: Vec<Part> = parts.iter().map(|p| p.part).collect();
+                assert_eq!(parsed, version.parts(), "parts of {}", version);
+            }
         }
+
+        // Original spelling of each part
+        let version = Version::from("v01.002-RC1 build 7").unwrap();
+        let (parts, trailing) = version.spelled_parts();
+        let spelled: Vec<(&str, &str)> = parts.iter().map(|p| (p.separator, p.text)).collect();
+        assert_eq!(
+            spelled,
+            [
+                ("", "v01"),
+                (".", "002"),
+                ("-", "RC1"),
+                (" ", "build"),
+                (" ", "7"),
+            ],
+        );
+        assert_eq!(trailing, "");
     }
 
     #[test]
-    fn manifest() {
-        let manifest = Manifest::default();
-        let mut version = Version::from("1.2.3").unwrap();
+    fn sort_key() {
+        // Keys sort like each version in the version set compares
+        for entry in COMBIS
+            .iter()
+            .filter(|c| c.3.map_or(true, |m| !m.gnu_ordering))
+        {
+            let (a, b) = entry.versions();
+            assert_eq!(
+                Cmp::from(a.sort_key().cmp(&b.sort_key())),
+                entry.2,
+                "Testing that key of {} is {} {}",
+                a,
+                entry.2.sign(),
+                b,
+            );
+        }
 
-        version.manifest = Some(&manifest);
-        assert_eq!(version.manifest(), Some(&manifest));
+        // Keys sort like random versions compare
+        let manifests = [
+            Manifest::default(),
+            Manifest::builder().max_depth(2).build(),
+            Manifest::builder().ignore_text(true).build(),
+            Manifest::builder().case_sensitive(true).build(),
+            Manifest::builder().split_alphanumeric(true).build(),
+            Manifest::builder().compare_numbers_only(true).build(),
+            Manifest::builder().underscore_update(true).build(),
+            Manifest::builder().longer_is_greater(true).build(),
+        ];
+        for seed in 1..=5000 {
+            let mut rng = Rng::new(seed);
+            let (a, b) = (rng.version(), rng.version());
+            for manifest in &manifests {
+                let (a, b) = match (
+                    Version::from_manifest(&a, manifest),
+                    Version::from_manifest(&b, manifest),
+                ) {
+                    (Some(a), Some(b)) => (a, b),
+                    _ => continue,
+                };
+                assert_eq!(
+                    Cmp::from(a.sort_key().cmp(&b.sort_key())),
+                    a.compare(&b),
+                    "Testing keys of {:?} and {:?} with {:?}",
+                    a.as_str(),
+                    b.as_str(),
+                    manifest,
+                );
+            }
+        }
 
-        version.manifest = None;
-        assert_eq!(version.manifest(), None);
+        // Sorting by key sorts by version
+        let mut versions: Vec<&str> = VERSIONS.iter().map(|v| v.0).collect();
+        versions.sort_by_key(|v| Version::from(v).unwrap().sort_key());
+        for pair in versions.windows(2) {
+            assert_ne!(crate::compare(pair[0], pair[1]), Ok(Cmp::Gt));
+        }
     }
 
     #[test]
-    fn has_manifest() {
-        let manifest = Manifest::default();
-        let mut version = Version::from("1.2.3").unwrap();
+    fn relations() {
+        let all = [Cmp::Eq, Cmp::Ne, Cmp::Lt, Cmp::Le, Cmp::Ge, Cmp::Gt];
 
-        version.manifest = Some(&manifest);
-        assert!(version.has_manifest());
+        // Relations match the compare_to truth table for each version in the version set
+        for entry in COMBIS.iter().filter(|c| c.3.is_none()) {
+            let (a, b) = entry.versions();
+            let relations = a.relations(&b);
+            assert!(relations.contains(&entry.2));
+            for operator in all {
+                assert_eq!(relations.contains(&operator), a.compare_to(&b, operator));
+            }
+        }
 
-        version.manifest = None;
-        assert!(!version.has_manifest());
+        let a = Version::from("1.2").unwrap();
+        let b = Version::from("1.3").unwrap();
+        assert_eq!(a.relations(&a), [Cmp::Eq, Cmp::Le, Cmp::Ge]);
+        assert_eq!(a.relations(&b), [Cmp::Ne, Cmp::Lt, Cmp::Le]);
+        assert_eq!(b.relations(&a), [Cmp::Ne, Cmp::Ge, Cmp::Gt]);
     }
 
     #[test]
-    fn set_manifest() {
-        let manifest = Manifest::default();
-        let mut version = Version::from("1.2.3").unwrap();
+    fn normalized_eq() {
+        for (a, b) in [
+            ("v1.2", " 1.2.0 "),
+            ("V1.2", "1.2"),
+            ("v1.2", "v1.2.0.0"),
+            ("  v1.2  rc1", "1.2 rc1"),
+            ("1.2", "1.2"),
+        ] {
+            let (a, b) = (Version::from(a).unwrap(), Version::from(b).unwrap());
+            assert!(a.normalized_eq(&b), "{} must normalize equal to {}", a, b);
+            assert!(b.normalized_eq(&a), "{} must normalize equal to {}", b, a);
+        }
 
-        version.set_manifest(Some(&manifest));
-        assert_eq!(version.manifest, Some(&manifest));
+        for (a, b) in [
+            ("v1.2", "1.2.1"),
+            ("v1.2", "1.2-rc1"),
+            ("version 1.2", "1.2"),
+            ("vv1.2", "1.2"),
+        ] {
+            let (a, b) = (Version::from(a).unwrap(), Version::from(b).unwrap());
+            assert!(
+                !a.normalized_eq(&b),
+                "{} must not normalize equal to {}",
+                a,
+                b
+            );
+        }
 
-        version.set_manifest(None);
-        assert_eq!(version.manifest, None);
+        // Plain equality doesn't strip the prefix
+        let (a, b) = (
+            Version::from("v1.2").unwrap(),
+            Version::from("1.2").unwrap(),
+        );
+        assert!(a.normalized_eq(&b));
+        assert_ne!(a, b);
     }
 
     #[test]
-    fn as_str() {
-        // Test for each test version
-        for version in VERSIONS {
-            // The input version string must be the same as the returned string
-            assert_eq!(Version::from(version.0).unwrap().as_str(), version.0);
+    fn between() {
+        let low = Version::from("1.0").unwrap();
+        let high = Version::from("2.0").unwrap();
+
+        for (version, inclusive, exclusive) in [
+            ("0.9", false, false),
+            ("1.0", true, false),
+            ("1.0.0", true, false),
+            ("1.0.1", true, true),
+            ("1.5", true, true),
+            ("2.0", true, false),
+            ("2", true, false),
+            ("2.0.1", false, false),
+        ] {
+            let ver = Version::from(version).unwrap();
+            assert_eq!(ver.between(&low, &high, true), inclusive, "{}", version);
+            assert_eq!(ver.between(&low, &high, false), exclusive, "{}", version);
         }
+
+        // An empty range
+        assert!(!Version::from("1.5").unwrap().between(&high, &low, true));
     }
 
     #[test]
-    fn part() {
-        // Test for each test version
-        for version in VERSIONS {
-            // Create a version object
-            let ver = Version::from(version.0).unwrap();
-
-            // Loop through each part
-            for i in 0..version.1 {
-                assert_eq!(ver.part(i), Ok(ver.parts[i]));
-            }
-
-            // A value outside the range must return an error
-            assert!(ver.part(version.1).is_err());
+    fn is_compatible_with() {
+        for (a, b, compatible) in [
+            ("1.5.0", "1.2.0", true),
+            ("1.2.0", "1.2.0", true),
+            ("1.2", "1.2.0", true),
+            ("1.9.9-rc1", "1.2.0", true),
+            ("2.0.0", "1.2.0", false),
+            ("1.1.0", "1.2.0", false),
+            ("0.1.5", "0.1.0", true),
+            ("0.2.1", "0.1.0", false),
+            ("1.0.0", "0.1.0", false),
+            ("0.0.3", "0.0.3", true),
+            ("0.0.4", "0.0.3", false),
+            ("0.0.0", "0.0", true),
+            ("0.1.0", "0.0", false),
+        ] {
+            assert_eq!(
+                Version::from(a)
+                    .unwrap()
+                    .is_compatible_with(Version::from(b).unwrap()),
+                compatible,
+                "Testing that {} is compatible with {}: {}",
+                a,
+                b,
+                compatible,
+            );
         }
     }
 
     #[test]
-    fn parts() {
-        // Test for each test version
-        for version in VERSIONS {
-            // The number of parts must match
-            assert_eq!(Version::from(version.0).unwrap().parts().len(), version.1);
+    fn from_scheme() {
+        let ver = Version::from_scheme("1.0.post1", Scheme::Pep440).unwrap();
+        assert_eq!(ver.scheme(), Scheme::Pep440);
+        assert_eq!(ver.as_str(), "1.0.post1");
+        assert_eq!(Version::from("1.0").unwrap().scheme(), Scheme::Generic);
+
+        // Invalid versions for the scheme
+        assert!(Version::from_scheme("1.0 final", Scheme::Pep440).is_none());
+        assert!(Version::from_scheme("1.0", Scheme::Semver).is_none());
+
+        // Versions that can't be parsed generically have no parts
+        let ver = Version::from_scheme("alpha", Scheme::Maven).unwrap();
+        assert!(ver.parts().is_empty());
+    }
+
+    #[test]
+    fn compare_scheme() {
+        let pep440 = |v| Version::from_scheme(v, Scheme::Pep440).unwrap();
+        let generic = |v| Version::from(v).unwrap();
+
+        // Versions compare following their scheme
+        assert_eq!(pep440("1.0.post1").compare(pep440("1.0")), Cmp::Gt);
+        assert_eq!(generic("1.0.post1").compare(generic("1.0")), Cmp::Lt);
+        assert!(pep440("1.0.dev1") < pep440("1.0a1"));
+        assert!(pep440("1.0") == pep440("1.0.0"));
+
+        // Mixed schemes compare generically, the same both ways
+        assert_eq!(pep440("1.0.post1").compare(generic("1.0")), Cmp::Lt);
+        assert_eq!(generic("1.0").compare(pep440("1.0.post1")), Cmp::Gt);
+        assert_eq!(pep440("1.0").compare(generic("1.0 final")), Cmp::Gt);
+        assert_eq!(pep440("1.0").try_compare(generic("1.0")), Err(()));
+
+        // Comparing is symmetric for any mix of schemes
+        for a in ["1.0", "1.0.post1", "1.0a1", "1.0-alpha", "2.0.0", "0"] {
+            for b in ["1.0", "1.0.post1", "1.0a1", "1.0-alpha", "2.0.0", "0"] {
+                for &a_scheme in Scheme::ALL {
+                    for &b_scheme in Scheme::ALL {
+                        let (lhs, rhs) = match (
+                            Version::from_scheme(a, a_scheme),
+                            Version::from_scheme(b, b_scheme),
+                        ) {
+                            (Some(lhs), Some(rhs)) => (lhs, rhs),
+                            _ => continue,
+                        };
+                        assert_eq!(
+                            lhs.compare(&rhs),
+                            rhs.compare(&lhs).flip(),
+                            "Testing {} with {:?} and {} with {:?}",
+                            a,
+                            a_scheme,
+                            b,
+                            b_scheme,
+                        );
+                    }
+                }
+            }
         }
     }
 
     #[test]
-    fn parts_max_depth() {
-        // Create a manifest
-        let mut manifest = Manifest::default();
+    fn compare_scheme_epoch() {
+        for (a, b, scheme) in [
+            ("1:1.0", "2.0", Scheme::Debian),
+            ("1:1.0", "2.0", Scheme::Arch),
+            ("1!1.0", "2.0", Scheme::Pep440),
+        ] {
+            let lhs = Version::from_scheme(a, scheme).unwrap();
+            let rhs = Version::from_scheme(b, scheme).unwrap();
+            assert_eq!(
+                lhs.compare(&rhs),
+                Cmp::Gt,
+                "{} > {} with {:?}",
+                a,
+                b,
+                scheme
+            );
+            assert_eq!(
+                rhs.compare(&lhs),
+                Cmp::Lt,
+                "{} < {} with {:?}",
+                b,
+                a,
+                scheme
+            );
+            assert!(lhs > rhs);
 
-        // Loop through a range of numbers
-        for depth in 0..5 {
-            // Set the maximum depth
-            manifest.max_depth = if depth > 0 { Some(depth) } else { None };
+            // The epoch is a regular number part without scheme
+            assert_eq!(
+                Version::from(a).unwrap().compare(Version::from(b).unwrap()),
+                Cmp::Lt,
+            );
+        }
 
-            // Test for each test version with the manifest
-            for version in VERSIONS {
-                // Create a version object, and count it's parts
-                let ver = Version::from_manifest(version.0, &manifest);
+        // An explicit zero epoch is equal to no epoch
+        let lhs = Version::from_scheme("0:1.0", Scheme::Debian).unwrap();
+        let rhs = Version::from_scheme("1.0", Scheme::Debian).unwrap();
+        assert!(lhs == rhs);
+        let lhs = Version::from_scheme("0!1.0", Scheme::Pep440).unwrap();
+        let rhs = Version::from_scheme("1.0", Scheme::Pep440).unwrap();
+        assert!(lhs == rhs);
+    }
 
-                // Some versions might be none, because not all of the start with a number when the
-                // maximum depth is 1. A version string with only text isn't allowed,
-                // resulting in none.
-                if ver.is_none() {
-                    continue;
-                }
+    #[test]
+    fn from_named() {
+        for (name, scheme) in [
+            ("default", Scheme::Generic),
+            ("generic", Scheme::Generic),
+            ("pep440", Scheme::Pep440),
+            ("Debian", Scheme::Debian),
+        ] {
+            let version = Version::from_named("1.2.3", name).unwrap();
+            assert_eq!(version.scheme(), scheme);
+            assert_eq!(version, Version::from_scheme("1.2.3", scheme).unwrap());
+        }
 
-                // Get the part count
-                let count = ver.unwrap().parts().len();
+        assert!(Version::from_named("1.2.3", "unknown").is_none());
+        assert!(Version::from_named("1.2", "semver").is_none());
+    }
 
-                // The number of parts must match
-                if depth == 0 {
-                    assert_eq!(count, version.1);
-                } else {
-                    assert_eq!(count, cmp::min(version.1, depth));
-                }
-            }
+    #[test]
+    fn epoch() {
+        for (version, scheme, epoch) in [
+            ("1!1.0", Scheme::Pep440, Some(1)),
+            ("1.0", Scheme::Pep440, Some(0)),
+            ("0!1.0", Scheme::Pep440, Some(0)),
+            ("2:1.0-1", Scheme::Debian, Some(2)),
+            ("1.0-1", Scheme::Debian, Some(0)),
+            ("3:1.0-1", Scheme::Arch, Some(3)),
+            ("1.0-1", Scheme::Arch, Some(0)),
+            ("1.0.0", Scheme::Semver, None),
+            ("1.0", Scheme::Maven, None),
+            ("1!1.0", Scheme::Generic, None),
+            ("1:1.0", Scheme::Generic, None),
+        ] {
+            let parsed = Version::from_scheme(version, scheme).unwrap();
+            assert_eq!(
+                parsed.epoch(),
+                epoch,
+                "epoch of {} with {:?}",
+                version,
+                scheme
+            );
         }
+
+        // Overflowing epochs saturate
+        let version = "99999999999999999999:1.0";
+        assert_eq!(
+            Version::from_scheme(version, Scheme::Debian)
+                .unwrap()
+                .epoch(),
+            Some(i64::MAX),
+        );
     }
 
     #[test]
-    fn parts_ignore_text() {
-        // Create a manifest
-        let mut manifest = Manifest::default();
-
-        // Try this for true and false
-        for ignore in &[true, false] {
-            // Set to ignore text
-            manifest.ignore_text = *ignore;
+    fn compare_by() {
+        // Default ordering of numbers, and of text by their value
+        let ordered = |lhs: &Part, rhs: &Part| match (lhs, rhs) {
+            (Part::Number(lhs), Part::Number(rhs)) => lhs.cmp(rhs),
+            (Part::Text(lhs), Part::Text(rhs)) => lhs.cmp(rhs),
+            (Part::Text(_), Part::Number(_)) => cmp::Ordering::Less,
+            (Part::Number(_), Part::Text(_)) => cmp::Ordering::Greater,
+        };
+        let reversed = |lhs: &Part, rhs: &Part| match (lhs, rhs) {
+            (Part::Text(lhs), Part::Text(rhs)) => rhs.cmp(lhs),
+            _ => ordered(lhs, rhs),
+        };
+
+        // Text differing pairs flip with reversed text ordering
+        for (a, b) in [("1.0-alpha", "1.0-beta"), ("2.rc.1", "2.beta.3")] {
+            let (a, b) = (Version::from(a).unwrap(), Version::from(b).unwrap());
+            assert_eq!(a.compare_by(&b, ordered), a.compare(&b));
+            assert_eq!(a.compare_by(&b, reversed), a.compare(&b).flip());
+        }
 
-            // Keep track whether any version passed with text
-            let mut had_text = false;
+        // Number differing pairs don't flip
+        let (a, b) = (
+            Version::from("1.2").unwrap(),
+            Version::from("1.10").unwrap(),
+        );
+        assert_eq!(a.compare_by(&b, reversed), Cmp::Lt);
 
-            // Test each test version
-            for version in VERSIONS {
-                // Create a version instance, and get it's parts
-                let ver = Version::from_manifest(version.0, &manifest).unwrap();
+        // Missing parts are a zero
+        let (a, b) = (
+            Version::from("1.2").unwrap(),
+            Version::from("1.2.0.0").unwrap(),
+        );
+        assert_eq!(a.compare_by(&b, ordered), Cmp::Eq);
+        let (a, b) = (
+            Version::from("1.2").unwrap(),
+            Version::from("1.2-rc").unwrap(),
+        );
+        assert_eq!(a.compare_by(&b, ordered), Cmp::Gt);
+    }
 
-                // Loop through all version parts
-                for part in ver.parts() {
-                    if let Part::Text(_) = part {
-                        // Set the flag
-                        had_text = true;
+    #[test]
+    fn try_compare() {
+        let pep440 = |v| Version::from_scheme(v, Scheme::Pep440).unwrap();
+        let generic = |v| Version::from(v).unwrap();
 
-                        // Break the loop if we already reached text when not ignored
-                        if !ignore {
-                            break;
-                        }
-                    }
-                }
-            }
+        assert_eq!(pep440("1.0.post1").try_compare(pep440("1.0")), Ok(Cmp::Gt));
+        assert_eq!(
+            generic("1.0.post1").try_compare(generic("1.0")),
+            Ok(Cmp::Lt)
+        );
 
-            // Assert had text
-            assert_eq!(had_text, !ignore);
-        }
+        // Versions with different schemes fail to compare
+        assert_eq!(pep440("1.0.post1").try_compare(generic("1.0")), Err(()));
+        assert_eq!(generic("1.0").try_compare(pep440("1.0.post1")), Err(()));
     }
 
     #[test]
-    fn compare() {
-        // Compare each version in the version set
-        for entry in COMBIS {
-            // Get both versions
-            let (a, b) = entry.versions();
+    fn compare_prerelease_keywords() {
+        // Walk the full keyword ordering, unknown text follows keywords, all precede the release
+        let ordered = [
+            "1.0-dev",
+            "1.0-alpha",
+            "1.0-beta",
+            "1.0-rc",
+            "1.0-snapshot",
+            "1.0-final",
+            "1.0-zeta",
+            "1.0",
+        ];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                let (a, b) = (Version::from(a).unwrap(), Version::from(b).unwrap());
+                assert_eq!(a.compare(&b), Cmp::from(i.cmp(&j)), "{} vs {}", a, b);
+            }
+        }
 
-            // Compare them
+        // Abbreviations are equal, case is ignored, trailing numbers break ties
+        for (a, b, cmp) in [
+            ("1.0-a1", "1.0-alpha1", Cmp::Eq),
+            ("1.0-a", "1.0-alpha", Cmp::Eq),
+            ("1.0-b2", "1.0-beta1", Cmp::Gt),
+            ("1.0-b", "1.0-alpha2", Cmp::Gt),
+            ("1.0-pre", "1.0-rc", Cmp::Eq),
+            ("1.0-pre1", "1.0-RC2", Cmp::Lt),
+            ("1.0-RC1", "1.0-rc1", Cmp::Eq),
+            ("1.0-RC1", "1.0-beta9", Cmp::Gt),
+            ("1.0-rc1", "1.0-rc2", Cmp::Lt),
+            ("1.0-rc9", "1.0-rc10", Cmp::Lt),
+            ("1.0-rc", "1.0-rc1", Cmp::Lt),
+            ("1.0.dev.1", "1.0.alpha", Cmp::Lt),
+        ] {
+            let (a, b) = (Version::from(a).unwrap(), Version::from(b).unwrap());
+            assert_eq!(a.compare(&b), cmp, "{} vs {}", a, b);
+            assert_eq!(b.compare(&a), cmp.flip(), "{} vs {}", b, a);
             assert_eq!(
-                a.compare(b),
-                entry.2.clone(),
-                "Testing that {} is {} {}",
-                entry.0,
-                entry.2.sign(),
-                entry.1,
+                Cmp::from(a.sort_key().cmp(&b.sort_key())),
+                cmp,
+                "{} vs {}",
+                a,
+                b
             );
         }
+
+        // Case sensitive ordering breaks remaining ties by the raw text
+        let manifest = Manifest::builder().case_sensitive(true).build();
+        for (a, b) in [("1.0-RC1", "1.0-rc1"), ("1.0-a1", "1.0-alpha1")] {
+            let a = Version::from_manifest(a, &manifest).unwrap();
+            let b = Version::from_manifest(b, &manifest).unwrap();
+            assert_eq!(a.compare(&b), Cmp::Lt, "{} vs {}", a, b);
+            assert_eq!(Cmp::from(a.sort_key().cmp(&b.sort_key())), Cmp::Lt);
+        }
+
+        // Override or disable the keyword table
+        let manifest = Manifest::builder()
+            .prerelease_keywords(&[("snapshot", 0), ("alpha", 1)])
+            .build();
+        let a = Version::from_manifest("1.0-snapshot", &manifest).unwrap();
+        let b = Version::from_manifest("1.0-alpha", &manifest).unwrap();
+        let c = Version::from_manifest("1.0-dev", &manifest).unwrap();
+        assert_eq!(a.compare(&b), Cmp::Lt);
+        assert_eq!(b.compare(&c), Cmp::Lt);
+
+        let manifest = Manifest::builder().prerelease_keywords(&[]).build();
+        let a = Version::from_manifest("1.0-dev", &manifest).unwrap();
+        let b = Version::from_manifest("1.0-alpha", &manifest).unwrap();
+        assert_eq!(a.compare(&b), Cmp::Gt);
+        assert_eq!(Cmp::from(a.sort_key().cmp(&b.sort_key())), a.compare(&b));
     }
 
     #[test]
@@ -743,10 +3863,20 @@ mod tests {
     fn debug() {
         assert_eq!(
             format!("{:?}", Version::from("1.2.3").unwrap()),
+            "Version { version: \"1.2.3\", parts: [Number(1), Number(2), Number(3)] }",
+        );
+        assert_eq!(
+            format!("{:#?}", Version::from("1.2").unwrap()),
+            "Version {\n    version: \"1.2\",\n    parts: [\n        Number(\n            1,\n        ),\n        Number(\n            2,\n        ),\n    ],\n}",
+        );
+
+        // The parts alone are shown through the parts slice
+        assert_eq!(
+            format!("{:?}", Version::from("1.2.3").unwrap().parts()),
             "[Number(1), Number(2), Number(3)]",
         );
         assert_eq!(
-            format!("{:#?}", Version::from("1.2.3").unwrap()),
+            format!("{:#?}", Version::from("1.2.3").unwrap().parts()),
             "[\n    Number(\n        1,\n    ),\n    Number(\n        2,\n    ),\n    Number(\n        3,\n    ),\n]",
         );
     }
@@ -768,6 +3898,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compare_to_operators() {
+        let operators = [Cmp::Eq, Cmp::Ne, Cmp::Lt, Cmp::Le, Cmp::Ge, Cmp::Gt];
+
+        // The operators holding for equal, less and greater pairs, in order
+        for (a, b, holds) in [
+            ("1.2", "1.2.0", [true, false, false, true, true, false]),
+            ("1.2", "1.3", [false, true, true, true, false, false]),
+            ("1.3", "1.2.9", [false, true, false, false, true, true]),
+        ] {
+            let (a, b) = (Version::from(a).unwrap(), Version::from(b).unwrap());
+            for (operator, holds) in operators.iter().zip(holds) {
+                assert_eq!(
+                    a.compare_to(&b, *operator),
+                    holds,
+                    "{} {}",
+                    a,
+                    operator.sign()
+                );
+                assert_eq!(a.try_compare_to(&b, *operator), Ok(holds));
+                assert_eq!(a.relations(&b).contains(operator), holds);
+            }
+        }
+
+        // Only trying fails on a scheme mismatch
+        let a = Version::from_scheme("1.0", Scheme::Pep440).unwrap();
+        let b = Version::from("1.0.post1").unwrap();
+        for operator in operators {
+            assert!(a.try_compare_to(&b, operator).is_err());
+            assert_eq!(
+                a.compare_to(&b, operator),
+                a.relations(&b).contains(&operator)
+            );
+        }
+    }
+
+    #[test]
+    fn partial_cmp_total() {
+        // Comparing always gives an ordering for each version in the version set
+        for entry in COMBIS {
+            let (a, b) = entry.versions();
+            assert!(a.partial_cmp(&b).is_some());
+            assert!(b.partial_cmp(&a).is_some());
+        }
+
+        // And for each pair of versions
+        for a in VERSIONS {
+            let a = Version::from(a.0).unwrap();
+            for b in VERSIONS {
+                let b = Version::from(b.0).unwrap();
+                assert_eq!(a.partial_cmp(&b), a.compare(&b).ord());
+                assert!(a.partial_cmp(&b).is_some());
+            }
+        }
+    }
+
     #[test]
     fn partial_eq() {
         // Compare each version in the version set
@@ -791,4 +3977,71 @@ mod tests {
         // Assert an exceptional case, compare to not equal
         assert!(Version::from("1.2").unwrap() != Version::from("1.2.3").unwrap());
     }
+
+    #[test]
+    fn ord() {
+        // Ordering matches comparing for each pair of versions
+        for a in VERSIONS {
+            let a = Version::from(a.0).unwrap();
+            for b in VERSIONS {
+                let b = Version::from(b.0).unwrap();
+                assert_eq!(Some(a.cmp(&b)), a.compare(&b).ord());
+                assert_eq!(a.cmp(&b) == std::cmp::Ordering::Equal, a == b);
+            }
+        }
+
+        // Sort and take the maximum
+        let mut versions: Vec<Version> = ["1.10", "1.2-rc1", "1.2", "1.9.1", "0.9"]
+            .iter()
+            .map(|v| Version::from(v).unwrap())
+            .collect();
+        assert_eq!(versions.iter().max().unwrap().as_str(), "1.10");
+        versions.sort();
+        let sorted: Vec<&str> = versions.iter().map(Version::as_str).collect();
+        assert_eq!(sorted, ["0.9", "1.2-rc1", "1.2", "1.9.1", "1.10"]);
+    }
+
+    #[test]
+    fn binary_heap() {
+        use std::collections::BinaryHeap;
+
+        // The heap pops the latest version first
+        let mut heap = BinaryHeap::new();
+        for version in [
+            "1.9.9",
+            "2.0.0",
+            "0.1",
+            "1.10.0-rc1",
+            "1.10.0",
+            "2.0.0-beta",
+        ] {
+            heap.push(Version::from(version).unwrap());
+        }
+        let mut popped = Vec::new();
+        while let Some(version) = heap.pop() {
+            popped.push(version.as_str().to_string());
+        }
+        assert_eq!(
+            popped,
+            [
+                "2.0.0",
+                "2.0.0-beta",
+                "1.10.0",
+                "1.10.0-rc1",
+                "1.9.9",
+                "0.1"
+            ],
+        );
+
+        // Versions sharing a scheme pop following it
+        let mut heap = BinaryHeap::new();
+        for version in ["1.0", "1.0.post1", "1.0.dev1", "1.0a1", "1!0.1"] {
+            heap.push(Version::from_scheme(version, Scheme::Pep440).unwrap());
+        }
+        let mut popped = Vec::new();
+        while let Some(version) = heap.pop() {
+            popped.push(version.as_str().to_string());
+        }
+        assert_eq!(popped, ["1!0.1", "1.0.post1", "1.0", "1.0a1", "1.0.dev1"]);
+    }
 }